@@ -0,0 +1,248 @@
+use crate::{
+    Body, Bytes, Error, HeaderMap, Method, QueryMeta, QueryOptions, Response, RunnerBuilder, Uri,
+};
+
+use tokio::runtime::Runtime;
+
+use std::future::Future;
+
+mod acl;
+mod agent;
+mod catalog;
+mod config;
+mod coordinate;
+mod event;
+mod health;
+mod kv_store;
+mod session;
+mod snapshot;
+mod status;
+mod txn;
+
+pub use agent::monitor::LogLines;
+pub use kv_store::watch::Updates;
+
+pub struct Runner {
+    inner: crate::Runner,
+    runtime: Runtime,
+}
+
+impl Runner {
+    pub fn builder() -> RunnerBuilder {
+        RunnerBuilder::new()
+    }
+
+    pub fn from_env() -> Result<Runner, Error> {
+        RunnerBuilder::from_env()?.build_blocking()
+    }
+
+    pub fn init(
+        uri: &str,
+        header_name: Option<&str>,
+        header_value: Option<&str>,
+    ) -> Result<Runner, Error> {
+        let mut builder = Runner::builder().address(uri);
+
+        if let (Some(header_name), Some(header_value)) = (header_name, header_value) {
+            builder = builder.sensitive_header(header_name, header_value);
+        }
+
+        builder.build_blocking()
+    }
+
+    pub(crate) fn new(inner: crate::Runner) -> Result<Runner, Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Runner { inner, runtime })
+    }
+
+    pub fn endpoint(&self) -> &Uri {
+        &self.inner.endpoint
+    }
+
+    pub fn request(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        headers: &HeaderMap,
+        body: impl Into<Body>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(
+            self.inner
+                .request(method, path, query, headers, body, options),
+        )
+    }
+
+    pub fn refresh_servers(&self) -> Result<(), Error> {
+        self.block_on(self.inner.refresh_servers())
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn execute<F>(&self, future: F) -> Result<Response<Bytes>, Error>
+    where
+        F: Future<Output = Result<Response<Body>, Error>>,
+    {
+        self.block_on(async {
+            let response = future.await?;
+            buffer(response).await
+        })
+    }
+
+    fn query<F>(&self, future: F) -> Result<(Response<Bytes>, QueryMeta), Error>
+    where
+        F: Future<Output = Result<(Response<Body>, QueryMeta), Error>>,
+    {
+        self.block_on(async {
+            let (response, meta) = future.await?;
+            Ok((buffer(response).await?, meta))
+        })
+    }
+}
+
+async fn buffer(response: Response<Body>) -> Result<Response<Bytes>, Error> {
+    let (parts, body) = response.into_parts();
+    let body = hyper::body::to_bytes(body).await?;
+
+    Ok(Response::from_parts(parts, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::consul::agent::monitor::MonitorOptions;
+    use crate::engine::consul::kv_store::watch::WatchOptions;
+    use crate::engine::consul::kv_store::KVPutOptions;
+    use std::time::Duration;
+
+    #[test]
+    fn key_get() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::init(&test_server.url(), None, None)?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .with_header("x-consul-index", "42")
+            .with_body("[{\"Key\":\"test_key\",\"Value\":\"dGVzdA==\"}]")
+            .create();
+        let (test_pair, test_meta) = test_runner.key_get("test_key", None)?;
+        mock.assert();
+        assert_eq!(test_pair.unwrap().value_str()?, Some("test"));
+        assert_eq!(test_meta.last_index, 42);
+        Ok(())
+    }
+
+    #[test]
+    fn key_put() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .build_blocking()?;
+        let mock = test_server
+            .mock("PUT", "/v1/kv/test_key")
+            .match_query("cas=42")
+            .match_body("test_value")
+            .with_body("true")
+            .create();
+        let test_kv = KVPutOptions::new().cas(42);
+        let test_stored = test_runner.key_put("test_key", "test_value", &test_kv)?;
+        mock.assert();
+        assert!(test_stored);
+        Ok(())
+    }
+
+    #[test]
+    fn save_snapshot_to() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::init(&test_server.url(), None, None)?;
+        let mock = test_server
+            .mock("GET", "/v1/snapshot")
+            .with_body("test_snapshot")
+            .create();
+        let mut test_writer = Vec::new();
+        let (test_written, _) = test_runner.save_snapshot_to(&mut test_writer, None)?;
+        mock.assert();
+        assert_eq!(test_written, 13);
+        assert_eq!(test_writer, b"test_snapshot");
+        Ok(())
+    }
+
+    #[test]
+    fn agent_monitor() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::init(&test_server.url(), None, None)?;
+        let mock = test_server
+            .mock("GET", "/v1/agent/monitor")
+            .match_query("loglevel=warn")
+            .with_body("test line one\ntest line two\n")
+            .create();
+        let test_monitor = MonitorOptions::new().loglevel("warn");
+        let test_lines = test_runner.agent_monitor(&test_monitor, None)?;
+        let test_lines: Vec<String> = test_lines.take(2).collect::<Result<_, _>>()?;
+        mock.assert();
+        assert_eq!(test_lines, ["test line one", "test line two"]);
+        Ok(())
+    }
+
+    #[test]
+    fn watch_key() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::init(&test_server.url(), None, None)?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(mockito::Matcher::UrlEncoded("index".into(), "0".into()))
+            .with_header("x-consul-index", "7")
+            .with_body("[{\"Key\":\"test_key\",\"ModifyIndex\":7,\"Value\":\"dGVzdA==\"}]")
+            .create();
+        let test_watch = WatchOptions::new().min_interval(Duration::ZERO);
+        let mut test_updates = test_runner.watch_key("test_key", &test_watch, None);
+        let test_pair = test_updates.next().unwrap()?.unwrap();
+        mock.assert();
+        assert_eq!(test_pair.value_str()?, Some("test"));
+        Ok(())
+    }
+
+    #[test]
+    fn status() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::init(&test_server.url(), None, None)?;
+        let mock = test_server
+            .mock("GET", "/v1/status/leader")
+            .with_status(500)
+            .create();
+        let test_error = test_runner.status_leader(None).unwrap_err();
+        mock.assert();
+        assert_eq!(
+            test_error.status(),
+            Some(hyper::StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn request() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new();
+        let test_runner = Runner::init(&test_server.url(), None, None)?;
+        let mock = test_server
+            .mock("GET", "/v1/internal/ui/services")
+            .match_query("test=value")
+            .with_body("[]")
+            .create();
+        let (test_response, _) = test_runner.request(
+            Method::GET,
+            "/v1/internal/ui/services",
+            &[("test", "value")],
+            &HeaderMap::new(),
+            Body::empty(),
+            None,
+        )?;
+        mock.assert();
+        assert_eq!(test_response.into_body(), "[]");
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{ACLLoginParams, ACLToken};
+
+mod auth_method;
+mod binding_rule;
+mod policy;
+mod role;
+mod token;
+
+impl Runner {
+    pub fn acl_bootstrap(&self) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_bootstrap())
+    }
+
+    pub fn acl_replication(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_replication(options))
+    }
+
+    pub fn acl_login(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_login(payload))
+    }
+
+    pub fn acl_logout(&self) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_logout())
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn acl_bootstrap_typed(&self) -> Result<ACLToken, Error> {
+        self.block_on(self.inner.acl_bootstrap_typed())
+    }
+
+    pub fn acl_login_typed(&self, params: &ACLLoginParams) -> Result<ACLToken, Error> {
+        self.block_on(self.inner.acl_login_typed(params))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+impl Runner {
+    pub fn acl_auth_method_create(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_auth_method_create(payload))
+    }
+
+    pub fn acl_auth_method_read(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_auth_method_read(name, options))
+    }
+
+    pub fn acl_auth_method_update(
+        &self,
+        name: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_auth_method_update(name, payload))
+    }
+
+    pub fn acl_auth_method_delete(&self, name: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_auth_method_delete(name))
+    }
+
+    pub fn acl_auth_methods(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_auth_methods(options))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+impl Runner {
+    pub fn acl_binding_rule_create(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_binding_rule_create(payload))
+    }
+
+    pub fn acl_binding_rule_read(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_binding_rule_read(id, options))
+    }
+
+    pub fn acl_binding_rule_update(
+        &self,
+        id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_binding_rule_update(id, payload))
+    }
+
+    pub fn acl_binding_rule_delete(&self, id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_binding_rule_delete(id))
+    }
+
+    pub fn acl_binding_rules(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_binding_rules(options))
+    }
+}
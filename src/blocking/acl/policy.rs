@@ -0,0 +1,84 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::ACLPolicy;
+
+impl Runner {
+    pub fn acl_policy_create(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_policy_create(payload))
+    }
+
+    pub fn acl_policy_read(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_policy_read(id, options))
+    }
+
+    pub fn acl_policy_read_name(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_policy_read_name(name, options))
+    }
+
+    pub fn acl_policy_update(
+        &self,
+        id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_policy_update(id, payload))
+    }
+
+    pub fn acl_policy_delete(&self, id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_policy_delete(id))
+    }
+
+    pub fn acl_policies(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_policies(options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn acl_policy_create_typed(&self, policy: &ACLPolicy) -> Result<ACLPolicy, Error> {
+        self.block_on(self.inner.acl_policy_create_typed(policy))
+    }
+
+    pub fn acl_policy_read_typed(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLPolicy, QueryMeta), Error> {
+        self.block_on(self.inner.acl_policy_read_typed(id, options))
+    }
+
+    pub fn acl_policy_read_name_typed(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLPolicy, QueryMeta), Error> {
+        self.block_on(self.inner.acl_policy_read_name_typed(name, options))
+    }
+
+    pub fn acl_policy_update_typed(
+        &self,
+        id: &str,
+        policy: &ACLPolicy,
+    ) -> Result<ACLPolicy, Error> {
+        self.block_on(self.inner.acl_policy_update_typed(id, policy))
+    }
+
+    pub fn acl_policies_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ACLPolicy>, QueryMeta), Error> {
+        self.block_on(self.inner.acl_policies_typed(options))
+    }
+}
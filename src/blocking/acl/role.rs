@@ -0,0 +1,80 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::ACLRole;
+
+impl Runner {
+    pub fn acl_role_create(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_role_create(payload))
+    }
+
+    pub fn acl_role_read(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_role_read(id, options))
+    }
+
+    pub fn acl_role_read_name(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_role_read_name(name, options))
+    }
+
+    pub fn acl_role_update(
+        &self,
+        id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_role_update(id, payload))
+    }
+
+    pub fn acl_role_delete(&self, id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_role_delete(id))
+    }
+
+    pub fn acl_roles(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_roles(options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn acl_role_create_typed(&self, role: &ACLRole) -> Result<ACLRole, Error> {
+        self.block_on(self.inner.acl_role_create_typed(role))
+    }
+
+    pub fn acl_role_read_typed(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLRole, QueryMeta), Error> {
+        self.block_on(self.inner.acl_role_read_typed(id, options))
+    }
+
+    pub fn acl_role_read_name_typed(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLRole, QueryMeta), Error> {
+        self.block_on(self.inner.acl_role_read_name_typed(name, options))
+    }
+
+    pub fn acl_role_update_typed(&self, id: &str, role: &ACLRole) -> Result<ACLRole, Error> {
+        self.block_on(self.inner.acl_role_update_typed(id, role))
+    }
+
+    pub fn acl_roles_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ACLRole>, QueryMeta), Error> {
+        self.block_on(self.inner.acl_roles_typed(options))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::ACLToken;
+
+impl Runner {
+    pub fn acl_token_create(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_token_create(payload))
+    }
+
+    pub fn acl_token_get(
+        &self,
+        accessor_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_token_get(accessor_id, options))
+    }
+
+    pub fn acl_token_self(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_token_self(options))
+    }
+
+    pub fn acl_token_update(
+        &self,
+        accessor_id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_token_update(accessor_id, payload))
+    }
+
+    pub fn acl_token_clone(&self, accessor_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_token_clone(accessor_id))
+    }
+
+    pub fn acl_token_delete(&self, accessor_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.acl_token_delete(accessor_id))
+    }
+
+    pub fn acl_tokens(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.acl_tokens(options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn acl_token_create_typed(&self, token: &ACLToken) -> Result<ACLToken, Error> {
+        self.block_on(self.inner.acl_token_create_typed(token))
+    }
+
+    pub fn acl_token_get_typed(
+        &self,
+        accessor_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLToken, QueryMeta), Error> {
+        self.block_on(self.inner.acl_token_get_typed(accessor_id, options))
+    }
+
+    pub fn acl_token_self_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLToken, QueryMeta), Error> {
+        self.block_on(self.inner.acl_token_self_typed(options))
+    }
+
+    pub fn acl_token_update_typed(
+        &self,
+        accessor_id: &str,
+        token: &ACLToken,
+    ) -> Result<ACLToken, Error> {
+        self.block_on(self.inner.acl_token_update_typed(accessor_id, token))
+    }
+
+    pub fn acl_token_clone_typed(&self, accessor_id: &str) -> Result<ACLToken, Error> {
+        self.block_on(self.inner.acl_token_clone_typed(accessor_id))
+    }
+
+    pub fn acl_tokens_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ACLToken>, QueryMeta), Error> {
+        self.block_on(self.inner.acl_tokens_typed(options))
+    }
+}
@@ -0,0 +1,63 @@
+use crate::blocking::Runner;
+use crate::{Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::AgentMember;
+
+mod checks;
+mod connect;
+pub(crate) mod monitor;
+mod service;
+
+impl Runner {
+    pub fn list_members(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.list_members(options))
+    }
+
+    pub fn agent_self(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_self(options))
+    }
+
+    pub fn agent_reload(&self) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_reload())
+    }
+
+    pub fn agent_maintenance(
+        &self,
+        enable: bool,
+        reason: Option<&str>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_maintenance(enable, reason))
+    }
+
+    pub fn agent_metrics(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_metrics(options))
+    }
+
+    pub fn agent_join(&self, address: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_join(address))
+    }
+
+    pub fn agent_leave(&self) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_leave())
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn list_members_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<AgentMember>, QueryMeta), Error> {
+        self.block_on(self.inner.list_members_typed(options))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{AgentCheckRegistration, HealthCheck};
+
+#[cfg(feature = "typed")]
+use std::collections::HashMap;
+
+impl Runner {
+    pub fn agent_checks(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_checks(options))
+    }
+
+    pub fn agent_check_register(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_check_register(payload))
+    }
+
+    pub fn agent_check_deregister(&self, check_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_check_deregister(check_id))
+    }
+
+    pub fn agent_check_pass(&self, check_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_check_pass(check_id))
+    }
+
+    pub fn agent_check_warn(&self, check_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_check_warn(check_id))
+    }
+
+    pub fn agent_check_fail(&self, check_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_check_fail(check_id))
+    }
+
+    pub fn agent_check_update(
+        &self,
+        check_id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_check_update(check_id, payload))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn agent_check_register_typed(
+        &self,
+        registration: &AgentCheckRegistration,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.agent_check_register_typed(registration))
+    }
+
+    pub fn agent_checks_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(HashMap<String, HealthCheck>, QueryMeta), Error> {
+        self.block_on(self.inner.agent_checks_typed(options))
+    }
+}
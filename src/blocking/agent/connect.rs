@@ -0,0 +1,23 @@
+use crate::blocking::Runner;
+use crate::{Bytes, Error, QueryMeta, QueryOptions, Response};
+
+impl Runner {
+    pub fn agent_connect_authorize(&self) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_connect_authorize())
+    }
+
+    pub fn agent_connect_ca_roots(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_connect_ca_roots(options))
+    }
+
+    pub fn agent_connect_ca_leaf(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_connect_ca_leaf(service, options))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::blocking::Runner;
+use crate::engine::consul::agent::monitor::{LogStream, MonitorOptions};
+use crate::{Error, QueryOptions};
+
+pub struct LogLines<'a> {
+    runner: &'a Runner,
+    stream: LogStream,
+}
+
+impl Runner {
+    pub fn agent_monitor(
+        &self,
+        monitor: &MonitorOptions,
+        options: Option<&QueryOptions>,
+    ) -> Result<LogLines<'_>, Error> {
+        let stream = self.block_on(self.inner.agent_monitor(monitor, options))?;
+
+        Ok(LogLines {
+            runner: self,
+            stream,
+        })
+    }
+}
+
+impl Iterator for LogLines<'_> {
+    type Item = Result<String, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runner.block_on(self.stream.next_line())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{AgentService, AgentServiceRegistration};
+
+#[cfg(feature = "typed")]
+use std::collections::HashMap;
+
+impl Runner {
+    pub fn agent_services(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_services(options))
+    }
+
+    pub fn agent_service_configuration(
+        &self,
+        service_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_service_configuration(service_id, options))
+    }
+
+    pub fn agent_health_service_name(
+        &self,
+        service_name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_health_service_name(service_name, options))
+    }
+
+    pub fn agent_health_service_id(
+        &self,
+        service_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.agent_health_service_id(service_id, options))
+    }
+
+    pub fn agent_service_register(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_service_register(payload))
+    }
+
+    pub fn agent_service_deregister(&self, service_id: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.agent_service_deregister(service_id))
+    }
+
+    pub fn agent_service_maintenance(
+        &self,
+        service_id: &str,
+        enable: bool,
+        reason: Option<&str>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(
+            self.inner
+                .agent_service_maintenance(service_id, enable, reason),
+        )
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn agent_service_register_typed(
+        &self,
+        registration: &AgentServiceRegistration,
+    ) -> Result<(), Error> {
+        self.block_on(self.inner.agent_service_register_typed(registration))
+    }
+
+    pub fn agent_services_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(HashMap<String, AgentService>, QueryMeta), Error> {
+        self.block_on(self.inner.agent_services_typed(options))
+    }
+
+    pub fn agent_service_configuration_typed(
+        &self,
+        service_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(AgentService, QueryMeta), Error> {
+        self.block_on(
+            self.inner
+                .agent_service_configuration_typed(service_id, options),
+        )
+    }
+}
@@ -0,0 +1,144 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{
+    CatalogNode, CatalogNodeServiceList, CatalogRegistration, CatalogService, Node,
+};
+
+#[cfg(feature = "typed")]
+use std::collections::HashMap;
+
+impl Runner {
+    pub fn catalog_register(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.catalog_register(payload))
+    }
+
+    pub fn catalog_deregister(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.catalog_deregister(payload))
+    }
+
+    pub fn catalog_datacenters(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_datacenters(options))
+    }
+
+    pub fn catalog_nodes(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_nodes(options))
+    }
+
+    pub fn catalog_services(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_services(options))
+    }
+
+    pub fn catalog_service_nodes(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_service_nodes(service, options))
+    }
+
+    pub fn catalog_connect_nodes(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_connect_nodes(service, options))
+    }
+
+    pub fn catalog_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_node(node, options))
+    }
+
+    pub fn catalog_node_services(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_node_services(node, options))
+    }
+
+    pub fn catalog_gateway_services(
+        &self,
+        gateway: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.catalog_gateway_services(gateway, options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn catalog_register_typed(
+        &self,
+        registration: &CatalogRegistration,
+    ) -> Result<bool, Error> {
+        self.block_on(self.inner.catalog_register_typed(registration))
+    }
+
+    pub fn catalog_datacenters_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<String>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_datacenters_typed(options))
+    }
+
+    pub fn catalog_nodes_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<Node>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_nodes_typed(options))
+    }
+
+    pub fn catalog_services_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(HashMap<String, Vec<String>>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_services_typed(options))
+    }
+
+    pub fn catalog_service_nodes_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CatalogService>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_service_nodes_typed(service, options))
+    }
+
+    pub fn catalog_connect_nodes_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CatalogService>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_connect_nodes_typed(service, options))
+    }
+
+    pub fn catalog_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<CatalogNode>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_node_typed(node, options))
+    }
+
+    pub fn catalog_node_services_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<CatalogNodeServiceList>, QueryMeta), Error> {
+        self.block_on(self.inner.catalog_node_services_typed(node, options))
+    }
+}
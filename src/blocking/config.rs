@@ -0,0 +1,39 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use serde::Serialize;
+
+impl Runner {
+    pub fn config_apply(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.config_apply(payload))
+    }
+
+    pub fn config_get(
+        &self,
+        kind: &str,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.config_get(kind, name, options))
+    }
+
+    pub fn config_list(
+        &self,
+        kind: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.config_list(kind, options))
+    }
+
+    pub fn config_delete(&self, kind: &str, name: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.config_delete(kind, name))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn config_apply_typed<T: Serialize>(&self, entry: &T) -> Result<bool, Error> {
+        self.block_on(self.inner.config_apply_typed(entry))
+    }
+}
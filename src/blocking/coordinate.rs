@@ -0,0 +1,55 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::CoordinateEntry;
+
+impl Runner {
+    pub fn coordinate_datacenters(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.coordinate_datacenters(options))
+    }
+
+    pub fn coordinate_nodes(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.coordinate_nodes(options))
+    }
+
+    pub fn coordinate_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.coordinate_node(node, options))
+    }
+
+    pub fn coordinate_update(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.coordinate_update(payload))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn coordinate_nodes_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CoordinateEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.coordinate_nodes_typed(options))
+    }
+
+    pub fn coordinate_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CoordinateEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.coordinate_node_typed(node, options))
+    }
+
+    pub fn coordinate_update_typed(&self, entry: &CoordinateEntry) -> Result<(), Error> {
+        self.block_on(self.inner.coordinate_update_typed(entry))
+    }
+}
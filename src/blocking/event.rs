@@ -0,0 +1,40 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::UserEvent;
+
+impl Runner {
+    pub fn event_fire(
+        &self,
+        name: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.event_fire(name, payload))
+    }
+
+    pub fn event_list(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.event_list(options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn event_fire_typed(
+        &self,
+        name: &str,
+        payload: impl Into<Body>,
+    ) -> Result<UserEvent, Error> {
+        self.block_on(self.inner.event_fire_typed(name, payload))
+    }
+
+    pub fn event_list_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<UserEvent>, QueryMeta), Error> {
+        self.block_on(self.inner.event_list_typed(options))
+    }
+}
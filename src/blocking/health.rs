@@ -0,0 +1,106 @@
+use crate::blocking::Runner;
+use crate::{Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{HealthCheck, ServiceEntry};
+
+impl Runner {
+    pub fn health_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.health_node(node, options))
+    }
+
+    pub fn health_checks(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.health_checks(service, options))
+    }
+
+    pub fn health_service(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.health_service(service, options))
+    }
+
+    pub fn health_connect(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.health_connect(service, options))
+    }
+
+    pub fn health_ingress(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.health_ingress(service, options))
+    }
+
+    pub fn health_state(
+        &self,
+        state: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.health_state(state, options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn health_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<HealthCheck>, QueryMeta), Error> {
+        self.block_on(self.inner.health_node_typed(node, options))
+    }
+
+    pub fn health_checks_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<HealthCheck>, QueryMeta), Error> {
+        self.block_on(self.inner.health_checks_typed(service, options))
+    }
+
+    pub fn health_service_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ServiceEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.health_service_typed(service, options))
+    }
+
+    pub fn health_connect_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ServiceEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.health_connect_typed(service, options))
+    }
+
+    pub fn health_ingress_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ServiceEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.health_ingress_typed(service, options))
+    }
+
+    pub fn health_state_typed(
+        &self,
+        state: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<HealthCheck>, QueryMeta), Error> {
+        self.block_on(self.inner.health_state_typed(state, options))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::blocking::Runner;
+use crate::engine::consul::kv_store::{KVDeleteOptions, KVPair, KVPutOptions};
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions};
+
+mod export;
+pub(crate) mod watch;
+
+impl Runner {
+    pub fn key_get(
+        &self,
+        key: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<KVPair>, QueryMeta), Error> {
+        self.block_on(self.inner.key_get(key, options))
+    }
+
+    pub fn key_get_raw(
+        &self,
+        key: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<Bytes>, QueryMeta), Error> {
+        self.block_on(self.inner.key_get_raw(key, options))
+    }
+
+    pub fn key_list(
+        &self,
+        prefix: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<KVPair>, QueryMeta), Error> {
+        self.block_on(self.inner.key_list(prefix, options))
+    }
+
+    pub fn key_keys(
+        &self,
+        prefix: &str,
+        separator: Option<&str>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<String>, QueryMeta), Error> {
+        self.block_on(self.inner.key_keys(prefix, separator, options))
+    }
+
+    pub fn key_put(
+        &self,
+        key: &str,
+        value: impl Into<Body>,
+        kv: &KVPutOptions,
+    ) -> Result<bool, Error> {
+        self.block_on(self.inner.key_put(key, value, kv))
+    }
+
+    pub fn key_delete(&self, key: &str, kv: &KVDeleteOptions) -> Result<bool, Error> {
+        self.block_on(self.inner.key_delete(key, kv))
+    }
+}
@@ -0,0 +1,22 @@
+use crate::blocking::Runner;
+use crate::engine::consul::kv_store::export::{ImportOptions, ImportReport, KVExportEntry};
+use crate::{Error, QueryOptions};
+
+impl Runner {
+    pub fn kv_export(
+        &self,
+        prefix: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<Vec<KVExportEntry>, Error> {
+        self.block_on(self.inner.kv_export(prefix, options))
+    }
+
+    pub fn kv_import(
+        &self,
+        entries: &[KVExportEntry],
+        import: &ImportOptions,
+        options: Option<&QueryOptions>,
+    ) -> Result<ImportReport, Error> {
+        self.block_on(self.inner.kv_import(entries, import, options))
+    }
+}
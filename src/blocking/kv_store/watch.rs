@@ -0,0 +1,43 @@
+use crate::blocking::Runner;
+use crate::engine::consul::kv_store::watch::{Watch, WatchOptions};
+use crate::engine::consul::kv_store::KVPair;
+use crate::{Error, QueryOptions};
+
+pub struct Updates<'a, T> {
+    runner: &'a Runner,
+    watch: Watch<T>,
+}
+
+impl Runner {
+    pub fn watch_key(
+        &self,
+        key: &str,
+        watch: &WatchOptions,
+        options: Option<&QueryOptions>,
+    ) -> Updates<'_, Option<KVPair>> {
+        Updates {
+            runner: self,
+            watch: self.inner.watch_key(key, watch, options),
+        }
+    }
+
+    pub fn watch_prefix(
+        &self,
+        prefix: &str,
+        watch: &WatchOptions,
+        options: Option<&QueryOptions>,
+    ) -> Updates<'_, Vec<KVPair>> {
+        Updates {
+            runner: self,
+            watch: self.inner.watch_prefix(prefix, watch, options),
+        }
+    }
+}
+
+impl<T> Iterator for Updates<'_, T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runner.block_on(self.watch.next_update())
+    }
+}
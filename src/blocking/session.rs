@@ -0,0 +1,76 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::SessionEntry;
+
+impl Runner {
+    pub fn session_create(&self, payload: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.session_create(payload))
+    }
+
+    pub fn session_destroy(&self, uuid: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.session_destroy(uuid))
+    }
+
+    pub fn session_info(
+        &self,
+        uuid: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.session_info(uuid, options))
+    }
+
+    pub fn session_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.session_node(node, options))
+    }
+
+    pub fn session_list(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.session_list(options))
+    }
+
+    pub fn session_renew(&self, uuid: &str) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.session_renew(uuid))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn session_create_typed(&self, session: &SessionEntry) -> Result<String, Error> {
+        self.block_on(self.inner.session_create_typed(session))
+    }
+
+    pub fn session_info_typed(
+        &self,
+        uuid: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<SessionEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.session_info_typed(uuid, options))
+    }
+
+    pub fn session_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<SessionEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.session_node_typed(node, options))
+    }
+
+    pub fn session_list_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<SessionEntry>, QueryMeta), Error> {
+        self.block_on(self.inner.session_list_typed(options))
+    }
+
+    pub fn session_renew_typed(&self, uuid: &str) -> Result<Vec<SessionEntry>, Error> {
+        self.block_on(self.inner.session_renew_typed(uuid))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::blocking::Runner;
+use crate::{Body, Bytes, Error, QueryMeta, QueryOptions, Response};
+
+use hyper::body::HttpBody;
+
+use std::io::Write;
+use std::path::Path;
+
+impl Runner {
+    pub fn snapshot_generate(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.snapshot_generate(options))
+    }
+
+    pub fn save_snapshot_to<W>(
+        &self,
+        writer: &mut W,
+        options: Option<&QueryOptions>,
+    ) -> Result<(u64, QueryMeta), Error>
+    where
+        W: Write + ?Sized,
+    {
+        let (response, meta) = self.block_on(self.inner.snapshot_generate(options))?;
+        let mut body = response.into_body();
+        let mut written = 0;
+
+        while let Some(chunk) = self.block_on(body.data()) {
+            let chunk = chunk?;
+            writer.write_all(&chunk)?;
+            written += chunk.len() as u64;
+        }
+
+        writer.flush()?;
+
+        Ok((written, meta))
+    }
+
+    pub fn save_snapshot_to_path(
+        &self,
+        path: impl AsRef<Path>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(u64, QueryMeta), Error> {
+        let path = path.as_ref();
+        let mut file = std::fs::File::create(path)?;
+
+        match self.save_snapshot_to(&mut file, options) {
+            Ok(saved) => {
+                file.sync_all()?;
+                Ok(saved)
+            }
+            Err(error) => {
+                drop(file);
+                let _ = std::fs::remove_file(path);
+                Err(error)
+            }
+        }
+    }
+
+    pub fn snapshot_restore(&self, snapshot: impl Into<Body>) -> Result<Response<Bytes>, Error> {
+        self.execute(self.inner.snapshot_restore(snapshot))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::blocking::Runner;
+use crate::{Bytes, Error, QueryMeta, QueryOptions, Response};
+
+impl Runner {
+    pub fn status_leader(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.status_leader(options))
+    }
+
+    pub fn status_peers(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Bytes>, QueryMeta), Error> {
+        self.query(self.inner.status_peers(options))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub fn status_leader_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(String, QueryMeta), Error> {
+        self.block_on(self.inner.status_leader_typed(options))
+    }
+
+    pub fn status_peers_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<String>, QueryMeta), Error> {
+        self.block_on(self.inner.status_peers_typed(options))
+    }
+}
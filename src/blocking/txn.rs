@@ -0,0 +1,9 @@
+use crate::blocking::Runner;
+use crate::engine::consul::txn::{Txn, TxnResponse};
+use crate::{Error, QueryOptions};
+
+impl Runner {
+    pub fn txn(&self, txn: &Txn, options: Option<&QueryOptions>) -> Result<TxnResponse, Error> {
+        self.block_on(self.inner.txn(txn, options))
+    }
+}
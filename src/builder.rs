@@ -0,0 +1,530 @@
+use crate::failover::{FailoverCallback, Servers};
+use crate::limit::Limiters;
+use crate::proxy::{Proxy, ProxyConnector};
+use crate::query::TOKEN_HEADER;
+use crate::resolve::DnsResolver;
+#[cfg(unix)]
+use crate::unix::UnixConnector;
+use crate::{
+    EndpointGroup, Error, FailoverEvent, HyperTransport, Limits, MetricsRecorder, Resolver,
+    RetryPolicy, Runner, TlsConfig, TlsConnector, Transport,
+};
+
+use http::uri::{Authority, Scheme};
+use hyper::client::connect::HttpConnector;
+use hyper::header::{HeaderName, HeaderValue};
+use hyper::{HeaderMap, Uri};
+
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEFAULT_ADDRESS: &str = "http://127.0.0.1:8500";
+const UNIX_SCHEME: &str = "unix://";
+#[cfg(unix)]
+const UNIX_ADDRESS: &str = "http://localhost";
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+pub struct RunnerBuilder {
+    addresses: Vec<String>,
+    token: Option<String>,
+    datacenter: Option<String>,
+    namespace: Option<String>,
+    partition: Option<String>,
+    headers: Vec<(String, String, bool)>,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    user_agent_suffix: Option<String>,
+    tls: TlsConfig,
+    proxy: Option<String>,
+    no_proxy: Option<String>,
+    resolver: Option<Arc<dyn Resolver>>,
+    resolve_overrides: Vec<(String, IpAddr)>,
+    transport: Option<Arc<dyn Transport>>,
+    retry: Option<RetryPolicy>,
+    server_refresh_interval: Option<Duration>,
+    on_failover: Option<FailoverCallback>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    limits: Limits,
+    group_limits: Vec<(EndpointGroup, Limits)>,
+}
+
+impl RunnerBuilder {
+    pub fn new() -> RunnerBuilder {
+        RunnerBuilder {
+            addresses: vec![String::from(DEFAULT_ADDRESS)],
+            token: None,
+            datacenter: None,
+            namespace: None,
+            partition: None,
+            headers: Vec::with_capacity(2),
+            connect_timeout: None,
+            request_timeout: None,
+            user_agent_suffix: None,
+            tls: TlsConfig::default(),
+            proxy: None,
+            no_proxy: None,
+            resolver: None,
+            resolve_overrides: Vec::new(),
+            transport: None,
+            retry: None,
+            server_refresh_interval: None,
+            on_failover: None,
+            metrics: None,
+            limits: Limits::default(),
+            group_limits: Vec::new(),
+        }
+    }
+
+    pub fn address(mut self, address: impl Into<String>) -> RunnerBuilder {
+        self.addresses = vec![address.into()];
+        self
+    }
+
+    pub fn addresses(
+        mut self,
+        addresses: impl IntoIterator<Item = impl Into<String>>,
+    ) -> RunnerBuilder {
+        self.addresses = addresses.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn token(mut self, token: impl Into<String>) -> RunnerBuilder {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn datacenter(mut self, datacenter: impl Into<String>) -> RunnerBuilder {
+        self.datacenter = Some(datacenter.into());
+        self
+    }
+
+    pub fn namespace(mut self, namespace: impl Into<String>) -> RunnerBuilder {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn partition(mut self, partition: impl Into<String>) -> RunnerBuilder {
+        self.partition = Some(partition.into());
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> RunnerBuilder {
+        self.headers.push((name.into(), value.into(), false));
+        self
+    }
+
+    pub fn sensitive_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> RunnerBuilder {
+        self.headers.push((name.into(), value.into(), true));
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> RunnerBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn request_timeout(mut self, timeout: Duration) -> RunnerBuilder {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> RunnerBuilder {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    pub fn tls_config(mut self, config: TlsConfig) -> RunnerBuilder {
+        self.tls = config;
+        self
+    }
+
+    pub fn ca_file(mut self, path: impl Into<PathBuf>) -> RunnerBuilder {
+        self.tls.ca_file = Some(path.into());
+        self
+    }
+
+    pub fn ca_path(mut self, path: impl Into<PathBuf>) -> RunnerBuilder {
+        self.tls.ca_path = Some(path.into());
+        self
+    }
+
+    pub fn client_cert(
+        mut self,
+        cert: impl Into<PathBuf>,
+        key: impl Into<PathBuf>,
+    ) -> RunnerBuilder {
+        self.tls.client_cert = Some(cert.into());
+        self.tls.client_key = Some(key.into());
+        self
+    }
+
+    pub fn tls_server_name(mut self, server_name: impl Into<String>) -> RunnerBuilder {
+        self.tls.server_name = Some(server_name.into());
+        self
+    }
+
+    pub fn tls_insecure_skip_verify(mut self, insecure_skip_verify: bool) -> RunnerBuilder {
+        self.tls.insecure_skip_verify = insecure_skip_verify;
+        self
+    }
+
+    pub fn proxy(mut self, proxy: impl Into<String>) -> RunnerBuilder {
+        self.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn no_proxy(mut self, no_proxy: impl Into<String>) -> RunnerBuilder {
+        self.no_proxy = Some(no_proxy.into());
+        self
+    }
+
+    pub fn resolver(mut self, resolver: impl Resolver) -> RunnerBuilder {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    pub fn resolve(mut self, host: impl Into<String>, address: IpAddr) -> RunnerBuilder {
+        self.resolve_overrides.push((host.into(), address));
+        self
+    }
+
+    pub fn retry_policy(mut self, retry: RetryPolicy) -> RunnerBuilder {
+        self.retry = Some(retry);
+        self
+    }
+
+    pub fn server_refresh_interval(mut self, interval: Duration) -> RunnerBuilder {
+        self.server_refresh_interval = Some(interval);
+        self
+    }
+
+    pub fn on_failover(
+        mut self,
+        on_failover: impl Fn(&FailoverEvent<'_>) + Send + Sync + 'static,
+    ) -> RunnerBuilder {
+        self.on_failover = Some(Arc::new(on_failover));
+        self
+    }
+
+    pub fn metrics_recorder(mut self, metrics: impl MetricsRecorder) -> RunnerBuilder {
+        self.metrics = Some(Arc::new(metrics));
+        self
+    }
+
+    pub fn limits(mut self, limits: Limits) -> RunnerBuilder {
+        self.limits = limits;
+        self
+    }
+
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> RunnerBuilder {
+        self.limits = self.limits.max_in_flight(max_in_flight);
+        self
+    }
+
+    pub fn max_blocking(mut self, max_blocking: usize) -> RunnerBuilder {
+        self.limits = self.limits.max_blocking(max_blocking);
+        self
+    }
+
+    pub fn rate_limit(mut self, rate: f64, burst: u32) -> RunnerBuilder {
+        self.limits = self.limits.rate_limit(rate, burst);
+        self
+    }
+
+    pub fn group_limits(mut self, group: EndpointGroup, limits: Limits) -> RunnerBuilder {
+        self.group_limits.push((group, limits));
+        self
+    }
+
+    pub fn transport(mut self, transport: impl Transport) -> RunnerBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn build(self) -> Result<Runner, Error> {
+        let address = match self.addresses.first() {
+            Some(address) => address.to_owned(),
+            None => {
+                let reason = String::from("at least one address is required");
+                return Err(Error::InvalidConfig(reason));
+            }
+        };
+
+        if let Some(path) = address.strip_prefix(UNIX_SCHEME) {
+            if self.addresses.len() > 1 || self.server_refresh_interval.is_some() {
+                let reason = format!("address {} does not support failover", address);
+                return Err(Error::InvalidConfig(reason));
+            }
+
+            let path = PathBuf::from(path);
+            return self.build_unix(path);
+        }
+
+        let endpoint = Uri::from_str(&address)?;
+        let mut servers = Vec::with_capacity(self.addresses.len());
+
+        for address in &self.addresses {
+            servers.push(parse_address(address)?);
+        }
+
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => {
+                let resolver = DnsResolver::new(&self.resolve_overrides, self.resolver.clone());
+                let mut http = HttpConnector::new_with_resolver(resolver);
+                http.enforce_http(false);
+                http.set_connect_timeout(self.connect_timeout);
+
+                let proxy = match &self.proxy {
+                    Some(proxy) => Some(Proxy::parse(proxy, self.no_proxy.as_deref())?),
+                    None => None,
+                };
+
+                let proxied = ProxyConnector::new(http, proxy);
+                let https = TlsConnector::new(proxied, &self.tls)?;
+                Arc::new(HyperTransport::new(https))
+            }
+        };
+
+        self.finish(endpoint, servers, transport)
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(self) -> Result<crate::blocking::Runner, Error> {
+        crate::blocking::Runner::new(self.build()?)
+    }
+
+    #[cfg(unix)]
+    fn build_unix(self, path: PathBuf) -> Result<Runner, Error> {
+        if path.as_os_str().is_empty() {
+            let reason = format!("address {} is missing a socket path", self.addresses[0]);
+            return Err(Error::InvalidConfig(reason));
+        }
+
+        let endpoint = Uri::from_static(UNIX_ADDRESS);
+        let servers = vec![(Scheme::HTTP, Authority::from_static("localhost"))];
+
+        let transport = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => {
+                let unix = UnixConnector::new(path).connect_timeout(self.connect_timeout);
+                Arc::new(HyperTransport::new(unix))
+            }
+        };
+
+        self.finish(endpoint, servers, transport)
+    }
+
+    #[cfg(not(unix))]
+    fn build_unix(self, _: PathBuf) -> Result<Runner, Error> {
+        let reason = format!("address {} requires unix domain sockets", self.addresses[0]);
+        Err(Error::InvalidConfig(reason))
+    }
+
+    fn finish(
+        self,
+        endpoint: Uri,
+        servers: Vec<(Scheme, Authority)>,
+        transport: Arc<dyn Transport>,
+    ) -> Result<Runner, Error> {
+        let default_headers = self.default_headers()?;
+        let limiters = Limiters::new(&self.limits, &self.group_limits)?;
+        let (scheme, authority) = servers[0].clone();
+        let servers = Servers::new(servers, self.server_refresh_interval, self.on_failover);
+
+        Ok(Runner {
+            transport,
+            endpoint,
+            default_headers,
+            scheme,
+            authority,
+            datacenter: self.datacenter,
+            namespace: self.namespace,
+            partition: self.partition,
+            request_timeout: self.request_timeout,
+            retry: self.retry,
+            servers: Arc::new(servers),
+            metrics: self.metrics,
+            limiters: Arc::new(limiters),
+        })
+    }
+
+    fn default_headers(&self) -> Result<HeaderMap, Error> {
+        let mut default_headers = HeaderMap::new();
+
+        default_headers.reserve(5);
+
+        let user_agent_name = http::header::USER_AGENT;
+        let user_agent_value = match &self.user_agent_suffix {
+            Some(suffix) => HeaderValue::from_str(&format!("{} {}", USER_AGENT, suffix))?,
+            None => HeaderValue::from_static(USER_AGENT),
+        };
+
+        default_headers.insert(user_agent_name, user_agent_value);
+
+        if let Some(token) = &self.token {
+            let mut value = HeaderValue::from_str(token)?;
+            value.set_sensitive(true);
+            default_headers.insert(TOKEN_HEADER, value);
+        }
+
+        for (name, value, sensitive) in &self.headers {
+            let header = HeaderName::from_str(name)?;
+            let mut value = HeaderValue::from_str(value)?;
+            value.set_sensitive(*sensitive);
+            default_headers.insert(header, value);
+        }
+
+        Ok(default_headers)
+    }
+}
+
+fn parse_address(address: &str) -> Result<(Scheme, Authority), Error> {
+    let uri_part = Uri::from_str(address)?.into_parts();
+
+    let scheme = match uri_part.scheme {
+        Some(scheme) => scheme,
+        None => {
+            let reason = format!("address {} is missing a scheme", address);
+            return Err(Error::InvalidConfig(reason));
+        }
+    };
+
+    let authority = match uri_part.authority {
+        Some(authority) => authority,
+        None => {
+            let reason = format!("address {} is missing an authority", address);
+            return Err(Error::InvalidConfig(reason));
+        }
+    };
+
+    Ok((scheme, authority))
+}
+
+impl Default for RunnerBuilder {
+    fn default() -> RunnerBuilder {
+        RunnerBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build() -> Result<(), Error> {
+        let test_runner = RunnerBuilder::new().build()?;
+        assert_eq!(test_runner.endpoint, "http://127.0.0.1:8500/");
+        assert_eq!(test_runner.scheme.as_str(), "http");
+        assert_eq!(test_runner.authority.as_str(), "127.0.0.1:8500");
+        assert_eq!(test_runner.default_headers.len(), 1);
+        assert_eq!(
+            test_runner
+                .default_headers
+                .get(http::header::USER_AGENT)
+                .unwrap(),
+            "sublight/0.1.0",
+        );
+        assert!(test_runner.datacenter.is_none());
+        assert!(test_runner.namespace.is_none());
+        assert!(test_runner.partition.is_none());
+        assert!(test_runner.request_timeout.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn build_with_options() -> Result<(), Error> {
+        let test_runner = RunnerBuilder::new()
+            .address("https://consul.example.com:8501")
+            .token("test_token")
+            .datacenter("test_dc")
+            .namespace("test_ns")
+            .partition("test_partition")
+            .header("test_header_name", "test_header_value")
+            .sensitive_header("test_sensitive_name", "test_sensitive_value")
+            .connect_timeout(Duration::from_secs(1))
+            .request_timeout(Duration::from_secs(5))
+            .user_agent_suffix("test_suffix/1.0")
+            .tls_server_name("server.dc1.consul")
+            .tls_insecure_skip_verify(true)
+            .build()?;
+        assert_eq!(test_runner.scheme.as_str(), "https");
+        assert_eq!(test_runner.authority.as_str(), "consul.example.com:8501");
+        assert_eq!(test_runner.default_headers.len(), 4);
+        assert_eq!(
+            test_runner
+                .default_headers
+                .get(http::header::USER_AGENT)
+                .unwrap(),
+            "sublight/0.1.0 test_suffix/1.0",
+        );
+        let test_token = test_runner.default_headers.get("x-consul-token").unwrap();
+        assert_eq!(test_token, "test_token");
+        assert!(test_token.is_sensitive());
+        let test_header = test_runner.default_headers.get("test_header_name").unwrap();
+        assert_eq!(test_header, "test_header_value");
+        assert!(!test_header.is_sensitive());
+        let test_sensitive = test_runner
+            .default_headers
+            .get("test_sensitive_name")
+            .unwrap();
+        assert!(test_sensitive.is_sensitive());
+        assert_eq!(test_runner.datacenter.as_deref(), Some("test_dc"));
+        assert_eq!(test_runner.namespace.as_deref(), Some("test_ns"));
+        assert_eq!(test_runner.partition.as_deref(), Some("test_partition"));
+        assert_eq!(test_runner.request_timeout, Some(Duration::from_secs(5)));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn build_with_resolve() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_port = test_server.socket_address().port();
+        let test_runner = RunnerBuilder::new()
+            .address(format!("http://consul.test:{}", test_port))
+            .resolve("consul.test", IpAddr::from([127, 0, 0, 1]))
+            .build()?;
+        let mock = test_server
+            .mock("GET", "/v1/status/leader")
+            .create_async()
+            .await;
+        test_runner.status_leader(None).await?;
+        mock.assert();
+        Ok(())
+    }
+
+    #[test]
+    fn build_invalid() {
+        let test_address = RunnerBuilder::new().address("127.0.0.1:8500").build();
+        assert!(matches!(test_address, Err(Error::InvalidConfig(_))));
+        let test_token = RunnerBuilder::new().token("test\ntoken").build();
+        assert!(matches!(test_token, Err(Error::InvalidConfig(_))));
+        let test_header = RunnerBuilder::new()
+            .header("test header", "test_value")
+            .build();
+        assert!(matches!(test_header, Err(Error::InvalidConfig(_))));
+        let test_ca_file = RunnerBuilder::new().ca_file("/test/missing/ca.pem").build();
+        assert!(matches!(test_ca_file, Err(Error::InvalidConfig(_))));
+        let test_ca_path = RunnerBuilder::new().ca_path("/test/missing/ca").build();
+        assert!(matches!(test_ca_path, Err(Error::InvalidConfig(_))));
+        let test_unix = RunnerBuilder::new().address("unix://").build();
+        assert!(matches!(test_unix, Err(Error::InvalidConfig(_))));
+        let test_unix_failover = RunnerBuilder::new()
+            .addresses(["unix:///test/consul_http.sock", "http://127.0.0.1:8500"])
+            .build();
+        assert!(matches!(test_unix_failover, Err(Error::InvalidConfig(_))));
+        let test_addresses = RunnerBuilder::new()
+            .addresses(["http://127.0.0.1:8500", "127.0.0.2:8500"])
+            .build();
+        assert!(matches!(test_addresses, Err(Error::InvalidConfig(_))));
+        let test_empty = RunnerBuilder::new().addresses(Vec::<String>::new()).build();
+        assert!(matches!(test_empty, Err(Error::InvalidConfig(_))));
+    }
+}
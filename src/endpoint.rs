@@ -0,0 +1,50 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointGroup {
+    Kv,
+    Catalog,
+    Acl,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Endpoint<'a> {
+    pub(crate) name: &'static str,
+    pub(crate) path: &'a str,
+}
+
+impl<'a> Endpoint<'a> {
+    pub(crate) const fn new(name: &'static str, path: &'a str) -> Endpoint<'a> {
+        Endpoint { name, path }
+    }
+
+    pub(crate) fn group(&self) -> Option<EndpointGroup> {
+        let path = self.path.strip_prefix("/v1/")?;
+
+        match path.split('/').next() {
+            Some("kv") | Some("txn") => Some(EndpointGroup::Kv),
+            Some("catalog") => Some(EndpointGroup::Catalog),
+            Some("acl") => Some(EndpointGroup::Acl),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group() {
+        let test_endpoint = Endpoint::new("key_get", "/v1/kv/{key}");
+        assert_eq!(test_endpoint.group(), Some(EndpointGroup::Kv));
+        let test_endpoint = Endpoint::new("txn", "/v1/txn");
+        assert_eq!(test_endpoint.group(), Some(EndpointGroup::Kv));
+        let test_endpoint = Endpoint::new("catalog_services", "/v1/catalog/services");
+        assert_eq!(test_endpoint.group(), Some(EndpointGroup::Catalog));
+        let test_endpoint = Endpoint::new("acl_token_get", "/v1/acl/token/{accessor_id}");
+        assert_eq!(test_endpoint.group(), Some(EndpointGroup::Acl));
+        let test_endpoint = Endpoint::new("status_leader", "/v1/status/leader");
+        assert_eq!(test_endpoint.group(), None);
+        let test_endpoint = Endpoint::new("request", "/v1/kvs");
+        assert_eq!(test_endpoint.group(), None);
+    }
+}
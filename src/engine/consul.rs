@@ -6,6 +6,9 @@ pub mod coordinate;
 pub mod event;
 pub mod health;
 pub mod kv_store;
+#[cfg(feature = "typed")]
+pub mod models;
 pub mod session;
 pub mod snapshot;
 pub mod status;
+pub mod txn;
@@ -1,4 +1,7 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{ACLLoginParams, ACLToken};
 
 pub mod auth_method;
 pub mod binding_rule;
@@ -12,44 +15,68 @@ impl Runner {
     pub async fn acl_bootstrap(&self) -> Result<Response<Body>, Error> {
         let path = format!("{}/bootstrap", ACL_BASE_URL);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_bootstrap", "/v1/acl/bootstrap");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_replication(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_replication(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/replication", ACL_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_replication", "/v1/acl/replication");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_login(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_login(&self, payload: impl Into<Body>) -> Result<Response<Body>, Error> {
         let path = format!("{}/login", ACL_BASE_URL);
         let method = Method::POST;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_login", "/v1/acl/login");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_logout(&self) -> Result<Response<Body>, Error> {
         let path = format!("{}/logout", ACL_BASE_URL);
         let method = Method::POST;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_logout", "/v1/acl/logout");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn acl_bootstrap_typed(&self) -> Result<ACLToken, Error> {
+        let response = self.acl_bootstrap().await?;
+        let token = self.decode(response).await?;
+        Ok(token)
+    }
+
+    pub async fn acl_login_typed(&self, params: &ACLLoginParams) -> Result<ACLToken, Error> {
+        let payload = self.encode(params)?;
+        let response = self.acl_login(payload).await?;
+        let token = self.decode(response).await?;
+        Ok(token)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,15 +84,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_bootstrap() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/bootstrap")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.acl_bootstrap().await?;
         mock.assert();
         assert!(mock.matched());
@@ -74,16 +102,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_replication() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/replication")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_replication().await?;
+            .create_async()
+            .await;
+        test_runner.acl_replication(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -91,16 +120,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_login() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("POST", "/v1/acl/login")
+            .match_body(r#"{"AuthMethod": "test_auth_method", "BearerToken": "test_token"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_login().await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_login(r#"{"AuthMethod": "test_auth_method", "BearerToken": "test_token"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -108,18 +141,49 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_logout() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("POST", "/v1/acl/logout")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.acl_logout().await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn acl_login_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("POST", "/v1/acl/login")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "AuthMethod": "test_auth_method",
+                "BearerToken": "test_token"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"AccessorID": "test_accessor_id", "SecretID": "test_secret_id", "Local": true}"#)
+            .create_async()
+            .await;
+        let test_params = ACLLoginParams {
+            auth_method: String::from("test_auth_method"),
+            bearer_token: String::from("test_token"),
+            ..ACLLoginParams::default()
+        };
+        let test_token = test_runner.acl_login_typed(&test_params).await?;
+        assert_eq!(test_token.accessor_id, "test_accessor_id");
+        assert_eq!(test_token.secret_id.as_deref(), Some("test_secret_id"));
+        assert!(test_token.local);
+        mock.assert();
+        Ok(())
+    }
 }
@@ -1,56 +1,77 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
 
 const ACL_AUTH_METHOD_BASE_URL: &str = "/v1/acl/auth-method";
 
 impl Runner {
-    pub async fn acl_auth_method_create(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_auth_method_create(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = ACL_AUTH_METHOD_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_auth_method_create", "/v1/acl/auth-method");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_auth_method_read(&self, name: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_auth_method_read(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", ACL_AUTH_METHOD_BASE_URL, name);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_auth_method_read", "/v1/acl/auth-method/{name}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_auth_method_update(&self, name: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_auth_method_update(
+        &self,
+        name: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_AUTH_METHOD_BASE_URL, name);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_auth_method_update", "/v1/acl/auth-method/{name}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_auth_method_delete(&self, name: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_AUTH_METHOD_BASE_URL, name);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_auth_method_delete", "/v1/acl/auth-method/{name}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_auth_methods(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_auth_methods(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", ACL_AUTH_METHOD_BASE_URL, "s");
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_auth_methods", "/v1/acl/auth-methods");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 }
 
@@ -61,16 +82,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_auth_method_create() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/auth-method")
+            .match_body(r#"{"Name": "test_auth_method"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_auth_method_create().await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_auth_method_create(r#"{"Name": "test_auth_method"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -78,17 +103,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_auth_method_read() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/auth-method/test_auth_method_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .acl_auth_method_read("test_auth_method_name")
+            .acl_auth_method_read("test_auth_method_name", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -97,17 +123,19 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_auth_method_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/auth-method/test_auth_method_name")
+            .match_body(r#"{"Name": "test_auth_method"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .acl_auth_method_update("test_auth_method_name")
+            .acl_auth_method_update("test_auth_method_name", r#"{"Name": "test_auth_method"}"#)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -116,15 +144,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_auth_method_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/acl/auth-method/test_auth_method_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
             .acl_auth_method_delete("test_auth_method_name")
             .await?;
@@ -135,16 +164,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_auth_methods() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/auth-methods")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_auth_methods().await?;
+            .create_async()
+            .await;
+        test_runner.acl_auth_methods(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -1,56 +1,77 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
 
 const ACL_BINDING_RULE_BASE_URL: &str = "/v1/acl/binding-rule";
 
 impl Runner {
-    pub async fn acl_binding_rule_create(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_binding_rule_create(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = ACL_BINDING_RULE_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_binding_rule_create", "/v1/acl/binding-rule");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_binding_rule_read(&self, id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_binding_rule_read(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", ACL_BINDING_RULE_BASE_URL, id);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_binding_rule_read", "/v1/acl/binding-rule/{id}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_binding_rule_update(&self, id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_binding_rule_update(
+        &self,
+        id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_BINDING_RULE_BASE_URL, id);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_binding_rule_update", "/v1/acl/binding-rule/{id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_binding_rule_delete(&self, id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_BINDING_RULE_BASE_URL, id);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_binding_rule_delete", "/v1/acl/binding-rule/{id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_binding_rules(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_binding_rules(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", ACL_BINDING_RULE_BASE_URL, "s");
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_binding_rules", "/v1/acl/binding-rules");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 }
 
@@ -61,16 +82,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_binding_rule_create() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/binding-rule")
+            .match_body(r#"{"AuthMethod": "test_auth_method"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_binding_rule_create().await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_binding_rule_create(r#"{"AuthMethod": "test_auth_method"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -78,17 +103,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_binding_rule_read() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/binding-rule/test_binding_rule_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .acl_binding_rule_read("test_binding_rule_id")
+            .acl_binding_rule_read("test_binding_rule_id", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -97,17 +123,22 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_binding_rule_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/binding-rule/test_binding_rule_id")
+            .match_body(r#"{"AuthMethod": "test_auth_method"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .acl_binding_rule_update("test_binding_rule_id")
+            .acl_binding_rule_update(
+                "test_binding_rule_id",
+                r#"{"AuthMethod": "test_auth_method"}"#,
+            )
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -116,15 +147,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_binding_rule_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/acl/binding-rule/test_binding_rule_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
             .acl_binding_rule_delete("test_binding_rule_id")
             .await?;
@@ -135,16 +167,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_binding_rules() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/binding-rules")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_binding_rules().await?;
+            .create_async()
+            .await;
+        test_runner.acl_binding_rules(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
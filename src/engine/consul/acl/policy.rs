@@ -1,66 +1,146 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::ACLPolicy;
 
 const ACL_POLICY_BASE_URL: &str = "/v1/acl/policy";
 
 impl Runner {
-    pub async fn acl_policy_create(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_policy_create(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = ACL_POLICY_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_policy_create", "/v1/acl/policy");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_policy_read(&self, id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_policy_read(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", ACL_POLICY_BASE_URL, id);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_policy_read", "/v1/acl/policy/{id}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_policy_read_name(&self, name: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_policy_read_name(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/name/{}", ACL_POLICY_BASE_URL, name);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_policy_read_name", "/v1/acl/policy/name/{name}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_policy_update(&self, id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_policy_update(
+        &self,
+        id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_POLICY_BASE_URL, id);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_policy_update", "/v1/acl/policy/{id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_policy_delete(&self, id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_POLICY_BASE_URL, id);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_policy_delete", "/v1/acl/policy/{id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_policies(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_policies(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", ACL_POLICY_BASE_URL.trim_end_matches('y'), "ies");
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_policies", "/v1/acl/policies");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn acl_policy_create_typed(&self, policy: &ACLPolicy) -> Result<ACLPolicy, Error> {
+        let payload = self.encode(policy)?;
+        let response = self.acl_policy_create(payload).await?;
+        let created = self.decode(response).await?;
+        Ok(created)
+    }
+
+    pub async fn acl_policy_read_typed(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLPolicy, QueryMeta), Error> {
+        let (response, meta) = self.acl_policy_read(id, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn acl_policy_read_name_typed(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLPolicy, QueryMeta), Error> {
+        let (response, meta) = self.acl_policy_read_name(name, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn acl_policy_update_typed(
+        &self,
+        id: &str,
+        policy: &ACLPolicy,
+    ) -> Result<ACLPolicy, Error> {
+        let payload = self.encode(policy)?;
+        let response = self.acl_policy_update(id, payload).await?;
+        let updated = self.decode(response).await?;
+        Ok(updated)
+    }
+
+    pub async fn acl_policies_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ACLPolicy>, QueryMeta), Error> {
+        let (response, meta) = self.acl_policies(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
@@ -71,16 +151,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_policy_create() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/policy")
+            .match_body(r#"{"Name": "test_policy"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_policy_create().await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_policy_create(r#"{"Name": "test_policy"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -88,16 +172,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_policy_read() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/policy/test_policy_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_policy_read("test_policy_id").await?;
+            .create_async()
+            .await;
+        test_runner.acl_policy_read("test_policy_id", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -105,16 +190,19 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_policy_read_name() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/policy/name/test_policy_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_policy_read_name("test_policy_name").await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_policy_read_name("test_policy_name", None)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -122,16 +210,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_policy_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/policy/test_policy_id")
+            .match_body(r#"{"Name": "test_policy"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_policy_update("test_policy_id").await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_policy_update("test_policy_id", r#"{"Name": "test_policy"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -139,15 +231,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_policy_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/acl/policy/test_policy_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.acl_policy_delete("test_policy_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -156,16 +249,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_policies() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/policies")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_policies().await?;
+            .create_async()
+            .await;
+        test_runner.acl_policies(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
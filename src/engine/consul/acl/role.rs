@@ -1,66 +1,139 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::ACLRole;
 
 const ACL_ROLE_BASE_URL: &str = "/v1/acl/role";
 
 impl Runner {
-    pub async fn acl_role_create(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_role_create(&self, payload: impl Into<Body>) -> Result<Response<Body>, Error> {
         let path = ACL_ROLE_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_role_create", "/v1/acl/role");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_role_read(&self, id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_role_read(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", ACL_ROLE_BASE_URL, id);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_role_read", "/v1/acl/role/{id}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_role_read_name(&self, name: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_role_read_name(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/name/{}", ACL_ROLE_BASE_URL, name);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_role_read_name", "/v1/acl/role/name/{name}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_role_update(&self, id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_role_update(
+        &self,
+        id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_ROLE_BASE_URL, id);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_role_update", "/v1/acl/role/{id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_role_delete(&self, id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_ROLE_BASE_URL, id);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_role_delete", "/v1/acl/role/{id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_roles(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_roles(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", ACL_ROLE_BASE_URL, "s");
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_roles", "/v1/acl/roles");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn acl_role_create_typed(&self, role: &ACLRole) -> Result<ACLRole, Error> {
+        let payload = self.encode(role)?;
+        let response = self.acl_role_create(payload).await?;
+        let created = self.decode(response).await?;
+        Ok(created)
+    }
+
+    pub async fn acl_role_read_typed(
+        &self,
+        id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLRole, QueryMeta), Error> {
+        let (response, meta) = self.acl_role_read(id, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn acl_role_read_name_typed(
+        &self,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLRole, QueryMeta), Error> {
+        let (response, meta) = self.acl_role_read_name(name, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn acl_role_update_typed(&self, id: &str, role: &ACLRole) -> Result<ACLRole, Error> {
+        let payload = self.encode(role)?;
+        let response = self.acl_role_update(id, payload).await?;
+        let updated = self.decode(response).await?;
+        Ok(updated)
+    }
+
+    pub async fn acl_roles_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ACLRole>, QueryMeta), Error> {
+        let (response, meta) = self.acl_roles(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
@@ -71,16 +144,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_role_create() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/role")
+            .match_body(r#"{"Name": "test_role"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_role_create().await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_role_create(r#"{"Name": "test_role"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -88,16 +165,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_role_read() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/role/test_role_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_role_read("test_role_id").await?;
+            .create_async()
+            .await;
+        test_runner.acl_role_read("test_role_id", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -105,16 +183,19 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_role_read_name() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/role/name/test_role_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_role_read_name("test_role_name").await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_role_read_name("test_role_name", None)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -122,16 +203,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_role_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/role/test_role_id")
+            .match_body(r#"{"Name": "test_role"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_role_update("test_role_id").await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_role_update("test_role_id", r#"{"Name": "test_role"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -139,15 +224,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_role_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/acl/role/test_role_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.acl_role_delete("test_role_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -156,16 +242,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_roles() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/roles")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_roles().await?;
+            .create_async()
+            .await;
+        test_runner.acl_roles(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
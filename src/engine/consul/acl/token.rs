@@ -1,76 +1,161 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::ACLToken;
 
 const ACL_TOKEN_BASE_URL: &str = "/v1/acl/token";
 
 impl Runner {
-    pub async fn acl_token_create(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_token_create(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = ACL_TOKEN_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_token_create", "/v1/acl/token");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_token_get(&self, accessor_id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_token_get(
+        &self,
+        accessor_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", ACL_TOKEN_BASE_URL, accessor_id);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_token_get", "/v1/acl/token/{accessor_id}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_token_self(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_token_self(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/self", ACL_TOKEN_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_token_self", "/v1/acl/token/self");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn acl_token_update(&self, accessor_id: &str) -> Result<Response<Body>, Error> {
+    pub async fn acl_token_update(
+        &self,
+        accessor_id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_TOKEN_BASE_URL, accessor_id);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_token_update", "/v1/acl/token/{accessor_id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_token_clone(&self, accessor_id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}/clone", ACL_TOKEN_BASE_URL, accessor_id);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_token_clone", "/v1/acl/token/{accessor_id}/clone");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn acl_token_delete(&self, accessor_id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}", ACL_TOKEN_BASE_URL, accessor_id);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("acl_token_delete", "/v1/acl/token/{accessor_id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn acl_tokens(&self) -> Result<Response<Body>, Error> {
+    pub async fn acl_tokens(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", ACL_TOKEN_BASE_URL, "s");
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("acl_tokens", "/v1/acl/tokens");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn acl_token_create_typed(&self, token: &ACLToken) -> Result<ACLToken, Error> {
+        let payload = self.encode(token)?;
+        let response = self.acl_token_create(payload).await?;
+        let created = self.decode(response).await?;
+        Ok(created)
+    }
+
+    pub async fn acl_token_get_typed(
+        &self,
+        accessor_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLToken, QueryMeta), Error> {
+        let (response, meta) = self.acl_token_get(accessor_id, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn acl_token_self_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(ACLToken, QueryMeta), Error> {
+        let (response, meta) = self.acl_token_self(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn acl_token_update_typed(
+        &self,
+        accessor_id: &str,
+        token: &ACLToken,
+    ) -> Result<ACLToken, Error> {
+        let payload = self.encode(token)?;
+        let response = self.acl_token_update(accessor_id, payload).await?;
+        let updated = self.decode(response).await?;
+        Ok(updated)
+    }
+
+    pub async fn acl_token_clone_typed(&self, accessor_id: &str) -> Result<ACLToken, Error> {
+        let response = self.acl_token_clone(accessor_id).await?;
+        let cloned = self.decode(response).await?;
+        Ok(cloned)
+    }
+
+    pub async fn acl_tokens_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ACLToken>, QueryMeta), Error> {
+        let (response, meta) = self.acl_tokens(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
@@ -81,16 +166,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_token_create() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/token")
+            .match_body(r#"{"Description": "test_token"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_token_create().await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_token_create(r#"{"Description": "test_token"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -98,16 +187,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_token_get() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/token/test_accessor_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_token_get("test_accessor_id").await?;
+            .create_async()
+            .await;
+        test_runner.acl_token_get("test_accessor_id", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -115,16 +205,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_token_self() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/token/self")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_token_self().await?;
+            .create_async()
+            .await;
+        test_runner.acl_token_self(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -132,16 +223,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_token_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/token/test_accessor_id")
+            .match_body(r#"{"Description": "test_description"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_token_update("test_accessor_id").await?;
+            .create_async()
+            .await;
+        test_runner
+            .acl_token_update("test_accessor_id", r#"{"Description": "test_description"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -149,15 +244,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_token_clone() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/acl/token/test_accessor_id/clone")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.acl_token_clone("test_accessor_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -166,15 +262,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_token_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/acl/token/test_accessor_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.acl_token_delete("test_accessor_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -183,16 +280,39 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn acl_tokens() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/acl/tokens")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.acl_tokens().await?;
+            .create_async()
+            .await;
+        test_runner.acl_tokens(None).await?;
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn acl_token_self_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/acl/token/self")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"AccessorID": "test_accessor_id", "SecretID": "test_secret_id", "Description": "test token", "Local": false}"#)
+            .create_async()
+            .await;
+        let (test_token, _) = test_runner.acl_token_self_typed(None).await?;
+        assert_eq!(test_token.accessor_id, "test_accessor_id");
+        assert_eq!(test_token.secret_id.as_deref(), Some("test_secret_id"));
+        assert!(!test_token.local);
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -1,93 +1,129 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Idempotent, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::AgentMember;
 
 pub mod checks;
 pub mod connect;
+pub mod monitor;
 pub mod service;
 
 const AGENT_BASE_URL: &str = "/v1/agent";
 
 impl Runner {
-    pub async fn list_members(&self) -> Result<Response<Body>, Error> {
+    pub async fn list_members(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/members", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("list_members", "/v1/agent/members");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn agent_self(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_self(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/self", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("agent_self", "/v1/agent/self");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
     pub async fn agent_reload(&self) -> Result<Response<Body>, Error> {
         let path = format!("{}/reload", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("agent_reload", "/v1/agent/reload");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn agent_maintenance(&self) -> Result<Response<Body>, Error> {
-        let path = format!("{}/maintentance", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+    pub async fn agent_maintenance(
+        &self,
+        enable: bool,
+        reason: Option<&str>,
+    ) -> Result<Response<Body>, Error> {
+        let mut query = vec![("enable", if enable { "true" } else { "false" })];
+
+        if let Some(reason) = reason {
+            query.push(("reason", reason));
+        }
+
+        let path = format!("{}/maintenance", AGENT_BASE_URL);
+        let uri = self.build_uri_with_query(&path, &query, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("agent_maintenance", "/v1/agent/maintenance");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn agent_metrics(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_metrics(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/metrics", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
-    }
-
-    pub async fn agent_monitor(&self) -> Result<Response<Body>, Error> {
-        let path = format!("{}/monitor", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
-        let method = Method::GET;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("agent_metrics", "/v1/agent/metrics");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
     pub async fn agent_join(&self, address: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/join/{}", AGENT_BASE_URL, address);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("agent_join", "/v1/agent/join/{address}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn agent_leave(&self) -> Result<Response<Body>, Error> {
         let path = format!("{}/leave", AGENT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("agent_leave", "/v1/agent/leave");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn list_members_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<AgentMember>, QueryMeta), Error> {
+        let (response, meta) = self.list_members(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,16 +131,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn list_members() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/members")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.list_members().await?;
+            .create_async()
+            .await;
+        test_runner.list_members(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -112,16 +149,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_self() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/self")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_self().await?;
+            .create_async()
+            .await;
+        test_runner.agent_self(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -129,15 +167,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_reload() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/reload")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.agent_reload().await?;
         mock.assert();
         assert!(mock.matched());
@@ -146,16 +185,23 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_maintenance() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
-            .mock("PUT", "/v1/agent/maintentance")
+            .mock("PUT", "/v1/agent/maintenance")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("enable".into(), "true".into()),
+                mockito::Matcher::UrlEncoded("reason".into(), "test reason".into()),
+            ]))
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_maintenance().await?;
+            .create_async()
+            .await;
+        test_runner
+            .agent_maintenance(true, Some("test reason"))
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -163,67 +209,76 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_metrics() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/metrics")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_metrics().await?;
+            .create_async()
+            .await;
+        test_runner.agent_metrics(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn agent_monitor() -> Result<(), Error> {
-        let mut test_server = Server::new();
+    async fn agent_join() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
-            .mock("GET", "/v1/agent/monitor")
+            .mock("PUT", "/v1/agent/join/1.2.3.4")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_monitor().await?;
+            .create_async()
+            .await;
+        test_runner.agent_join("1.2.3.4").await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn agent_join() -> Result<(), Error> {
-        let mut test_server = Server::new();
+    async fn agent_leave() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
-            .mock("PUT", "/v1/agent/join/1.2.3.4")
+            .mock("PUT", "/v1/agent/leave")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_join("1.2.3.4").await?;
+            .create_async()
+            .await;
+        test_runner.agent_leave().await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
 
+    #[cfg(feature = "typed")]
     #[tokio::test(flavor = "multi_thread")]
-    async fn agent_leave() -> Result<(), Error> {
-        let mut test_server = Server::new();
+    async fn list_members_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
-            .mock("PUT", "/v1/agent/leave")
+            .mock("GET", "/v1/agent/members")
             .with_status(200)
-            .with_header("user-agent", "sublight/0.1.0")
-            .with_body("")
-            .create();
-        test_runner.agent_leave().await?;
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"Name": "test_member", "Addr": "10.1.10.12", "Port": 8301, "Tags": {"role": "consul"}, "Status": 1}]"#)
+            .create_async()
+            .await;
+        let (test_members, _) = test_runner.list_members_typed(None).await?;
+        assert_eq!(test_members.len(), 1);
+        assert_eq!(test_members[0].name, "test_member");
+        assert_eq!(test_members[0].port, 8301);
+        assert_eq!(test_members[0].status, 1);
         mock.assert();
         assert!(mock.matched());
         Ok(())
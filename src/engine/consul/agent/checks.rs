@@ -1,79 +1,132 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Idempotent, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{AgentCheckRegistration, HealthCheck};
+
+#[cfg(feature = "typed")]
+use std::collections::HashMap;
 
 const AGENT_CHECKS_BASE_URL: &str = "/v1/agent/check";
 
 impl Runner {
-    pub async fn agent_checks(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_checks(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", AGENT_CHECKS_BASE_URL, "s");
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("agent_checks", "/v1/agent/checks");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn agent_check_register(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_check_register(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/register", AGENT_CHECKS_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("agent_check_register", "/v1/agent/check/register");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn agent_check_deregister(&self, check_id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/deregister/{}", AGENT_CHECKS_BASE_URL, check_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new(
+            "agent_check_deregister",
+            "/v1/agent/check/deregister/{check_id}",
+        );
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn agent_check_pass(&self, check_id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/pass/{}", AGENT_CHECKS_BASE_URL, check_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("agent_check_pass", "/v1/agent/check/pass/{check_id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn agent_check_warn(&self, check_id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/warn/{}", AGENT_CHECKS_BASE_URL, check_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("agent_check_warn", "/v1/agent/check/warn/{check_id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn agent_check_fail(&self, check_id: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/fail/{}", AGENT_CHECKS_BASE_URL, check_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("agent_check_fail", "/v1/agent/check/fail/{check_id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn agent_check_update(&self, check_id: &str) -> Result<Response<Body>, Error> {
+    pub async fn agent_check_update(
+        &self,
+        check_id: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/update/{}", AGENT_CHECKS_BASE_URL, check_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let body = payload.into();
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("agent_check_update", "/v1/agent/check/update/{check_id}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn agent_check_register_typed(
+        &self,
+        registration: &AgentCheckRegistration,
+    ) -> Result<(), Error> {
+        let payload = self.encode(registration)?;
+        self.agent_check_register(payload).await?;
+        Ok(())
+    }
+
+    pub async fn agent_checks_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(HashMap<String, HealthCheck>, QueryMeta), Error> {
+        let (response, meta) = self.agent_checks(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,16 +134,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_checks() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/checks")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_checks().await?;
+            .create_async()
+            .await;
+        test_runner.agent_checks(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -98,16 +152,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_check_register() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/check/register")
+            .match_body(r#"{"Name": "test_check", "TTL": "15s"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_check_register().await?;
+            .create_async()
+            .await;
+        test_runner
+            .agent_check_register(r#"{"Name": "test_check", "TTL": "15s"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -115,15 +173,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_check_deregister() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/check/deregister/test_check_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.agent_check_deregister("test_check_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -132,15 +191,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_check_pass() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/check/pass/test_check_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.agent_check_pass("test_check_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -149,15 +209,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_check_warn() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/check/warn/test_check_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.agent_check_warn("test_check_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -166,15 +227,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_check_fail() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/check/fail/test_check_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.agent_check_fail("test_check_id").await?;
         mock.assert();
         assert!(mock.matched());
@@ -183,16 +245,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_check_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/check/update/test_check_id")
+            .match_body(r#"{"Status": "passing"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_check_update("test_check_id").await?;
+            .create_async()
+            .await;
+        test_runner
+            .agent_check_update("test_check_id", r#"{"Status": "passing"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -1,36 +1,51 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
 
 const AGENT_CONNECT_BASE_URL: &str = "/v1/agent/connect";
 
 impl Runner {
     pub async fn agent_connect_authorize(&self) -> Result<Response<Body>, Error> {
         let path = format!("{}/authorize", AGENT_CONNECT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("agent_connect_authorize", "/v1/agent/connect/authorize");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn agent_connect_ca_roots(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_connect_ca_roots(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/ca/roots", AGENT_CONNECT_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("agent_connect_ca_roots", "/v1/agent/connect/ca/roots");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn agent_connect_ca_leaf(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn agent_connect_ca_leaf(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/ca/leaf/{}", AGENT_CONNECT_BASE_URL, service);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new(
+            "agent_connect_ca_leaf",
+            "/v1/agent/connect/ca/leaf/{service}",
+        );
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 }
 
@@ -41,15 +56,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_connect_authorize() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/connect/authorize")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.agent_connect_authorize().await?;
         mock.assert();
         assert!(mock.matched());
@@ -58,16 +74,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_connect_ca_roots() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/connect/ca/roots")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_connect_ca_roots().await?;
+            .create_async()
+            .await;
+        test_runner.agent_connect_ca_roots(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -75,16 +92,19 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_connect_ca_leaf() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/connect/ca/leaf/test_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_connect_ca_leaf("test_service").await?;
+            .create_async()
+            .await;
+        test_runner
+            .agent_connect_ca_leaf("test_service", None)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -0,0 +1,284 @@
+use crate::{Body, Endpoint, Error, Method, QueryOptions, Response, RetryPolicy, Runner};
+
+use futures_core::Stream;
+use hyper::body::{Bytes, HttpBody};
+use hyper::StatusCode;
+use tokio::time::Sleep;
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+const AGENT_MONITOR_PATH: &str = "/v1/agent/monitor";
+
+type Connecting = Pin<Box<dyn Future<Output = Result<Response<Body>, Error>> + Send>>;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MonitorOptions {
+    pub loglevel: Option<String>,
+    pub logjson: bool,
+}
+
+impl MonitorOptions {
+    pub fn new() -> MonitorOptions {
+        MonitorOptions::default()
+    }
+
+    pub fn loglevel(mut self, loglevel: impl Into<String>) -> MonitorOptions {
+        self.loglevel = Some(loglevel.into());
+        self
+    }
+
+    pub fn logjson(mut self, logjson: bool) -> MonitorOptions {
+        self.logjson = logjson;
+        self
+    }
+}
+
+enum State {
+    Reading(Body),
+    Connecting(Connecting),
+    Waiting(Pin<Box<Sleep>>),
+    Done,
+}
+
+pub struct LogStream {
+    runner: Runner,
+    monitor: MonitorOptions,
+    options: Option<QueryOptions>,
+    backoff: RetryPolicy,
+    failures: u32,
+    state: State,
+    partial: Vec<u8>,
+    lines: VecDeque<String>,
+}
+
+impl Runner {
+    pub async fn agent_monitor(
+        &self,
+        monitor: &MonitorOptions,
+        options: Option<&QueryOptions>,
+    ) -> Result<LogStream, Error> {
+        let response = self.agent_monitor_connect(monitor, options).await?;
+
+        Ok(LogStream {
+            runner: self.clone(),
+            monitor: monitor.clone(),
+            options: options.cloned(),
+            backoff: self.retry.clone().unwrap_or_default(),
+            failures: 0,
+            state: State::Reading(response.into_body()),
+            partial: Vec::new(),
+            lines: VecDeque::new(),
+        })
+    }
+
+    async fn agent_monitor_connect(
+        &self,
+        monitor: &MonitorOptions,
+        options: Option<&QueryOptions>,
+    ) -> Result<Response<Body>, Error> {
+        let mut query = Vec::with_capacity(2);
+
+        if let Some(loglevel) = &monitor.loglevel {
+            query.push(("loglevel", loglevel.as_str()));
+        }
+
+        if monitor.logjson {
+            query.push(("logjson", "true"));
+        }
+
+        let path = AGENT_MONITOR_PATH;
+        let method = Method::GET;
+        let uri = self.build_uri_with_query(path, &query, options).await?;
+        let body = Body::empty();
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("agent_monitor", "/v1/agent/monitor");
+        let response = self.execute(&endpoint, request).await?;
+        Ok(response)
+    }
+}
+
+impl LogStream {
+    pub async fn next_line(&mut self) -> Option<Result<String, Error>> {
+        std::future::poll_fn(|context| Pin::new(&mut *self).poll_next(context)).await
+    }
+
+    fn push(&mut self, chunk: &Bytes) {
+        self.partial.extend_from_slice(chunk);
+
+        while let Some(end) = self.partial.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=end).collect();
+            self.emit(&line[..end]);
+        }
+    }
+
+    fn emit(&mut self, line: &[u8]) {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        self.lines
+            .push_back(String::from_utf8_lossy(line).into_owned());
+    }
+
+    fn disconnected(&mut self) {
+        if !self.partial.is_empty() {
+            let line = std::mem::take(&mut self.partial);
+            self.emit(&line);
+        }
+
+        self.failures = self.failures.saturating_add(1);
+        let delay = self.backoff.delay(self.failures);
+        self.state = State::Waiting(Box::pin(tokio::time::sleep(delay)));
+    }
+
+    fn reconnect(&mut self) {
+        let runner = self.runner.clone();
+        let monitor = self.monitor.clone();
+        let options = self.options.clone();
+
+        self.state = State::Connecting(Box::pin(async move {
+            runner
+                .agent_monitor_connect(&monitor, options.as_ref())
+                .await
+        }));
+    }
+}
+
+impl Stream for LogStream {
+    type Item = Result<String, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = &mut *self;
+
+        loop {
+            if let Some(line) = stream.lines.pop_front() {
+                return Poll::Ready(Some(Ok(line)));
+            }
+
+            match &mut stream.state {
+                State::Reading(body) => match ready!(Pin::new(body).poll_data(context)) {
+                    Some(Ok(chunk)) => {
+                        stream.failures = 0;
+                        stream.push(&chunk);
+                    }
+                    Some(Err(_)) | None => stream.disconnected(),
+                },
+                State::Connecting(connecting) => match ready!(connecting.as_mut().poll(context)) {
+                    Ok(response) => stream.state = State::Reading(response.into_body()),
+                    Err(error) if is_permanent(&error) => {
+                        stream.state = State::Done;
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                    Err(error) => {
+                        stream.disconnected();
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                },
+                State::Waiting(sleep) => {
+                    ready!(sleep.as_mut().poll(context));
+                    stream.reconnect();
+                }
+                State::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+fn is_permanent(error: &Error) -> bool {
+    match error {
+        Error::Status { status, .. } => {
+            status.is_client_error() && *status != StatusCode::TOO_MANY_REQUESTS
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn agent_monitor() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/agent/monitor")
+            .match_query("loglevel=debug&logjson=true")
+            .with_status(200)
+            .with_body("test line one\r\ntest line two")
+            .expect(2)
+            .create_async()
+            .await;
+        let test_monitor = MonitorOptions::new().loglevel("debug").logjson(true);
+        let mut test_stream = test_runner.agent_monitor(&test_monitor, None).await?;
+        for test_line in ["test line one", "test line two", "test line one"] {
+            assert_eq!(test_stream.next_line().await.unwrap()?, test_line);
+        }
+        mock.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn agent_monitor_error() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/agent/monitor")
+            .with_status(403)
+            .create_async()
+            .await;
+        let test_monitor = MonitorOptions::new();
+        let test_error = test_runner.agent_monitor(&test_monitor, None).await;
+        assert!(matches!(test_error, Err(Error::Status { .. })));
+        mock.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn agent_monitor_spawn() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .retry_policy(RetryPolicy::new().base_delay(std::time::Duration::from_millis(1)))
+            .build()?;
+        let mock = test_server
+            .mock("GET", "/v1/agent/monitor")
+            .with_status(200)
+            .with_body("test line")
+            .expect(1)
+            .create_async()
+            .await;
+        let mut test_stream = test_runner
+            .agent_monitor(&MonitorOptions::new(), None)
+            .await?;
+        mock.assert();
+        mock.remove();
+        let test_forbidden = test_server
+            .mock("GET", "/v1/agent/monitor")
+            .with_status(403)
+            .with_body("Permission denied")
+            .expect(1)
+            .create_async()
+            .await;
+        let test_lines = tokio::spawn(async move {
+            let mut test_lines = Vec::new();
+            while let Some(test_line) = test_stream.next_line().await {
+                test_lines.push(test_line);
+            }
+            test_lines
+        })
+        .await
+        .unwrap();
+        assert_eq!(test_lines.len(), 2);
+        assert_eq!(test_lines[0].as_deref().ok(), Some("test line"));
+        assert!(matches!(
+            test_lines[1],
+            Err(Error::Status { status, .. }) if status == StatusCode::FORBIDDEN
+        ));
+        test_forbidden.assert();
+        Ok(())
+    }
+}
@@ -1,69 +1,105 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Idempotent, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{AgentService, AgentServiceRegistration};
+
+#[cfg(feature = "typed")]
+use std::collections::HashMap;
 
 const AGENT_SERVICE_BASE_URL: &str = "/v1/agent/service";
 
 impl Runner {
-    pub async fn agent_services(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_services(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}{}", AGENT_SERVICE_BASE_URL, "s");
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("agent_services", "/v1/agent/services");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
     pub async fn agent_service_configuration(
         &self,
         service_id: &str,
-    ) -> Result<Response<Body>, Error> {
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", AGENT_SERVICE_BASE_URL, service_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new(
+            "agent_service_configuration",
+            "/v1/agent/service/{service_id}",
+        );
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
     pub async fn agent_health_service_name(
         &self,
         service_name: &str,
-    ) -> Result<Response<Body>, Error> {
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!(
             "{}/health/service/name/{}",
             AGENT_SERVICE_BASE_URL.trim_end_matches("/service"),
             service_name,
         );
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new(
+            "agent_health_service_name",
+            "/v1/agent/health/service/name/{service_name}",
+        );
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn agent_health_service_id(&self, service_id: &str) -> Result<Response<Body>, Error> {
+    pub async fn agent_health_service_id(
+        &self,
+        service_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!(
             "{}/health/service/id/{}",
             AGENT_SERVICE_BASE_URL.trim_end_matches("/service"),
             service_id,
         );
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let method = Method::GET;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new(
+            "agent_health_service_id",
+            "/v1/agent/health/service/id/{service_id}",
+        );
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn agent_service_register(&self) -> Result<Response<Body>, Error> {
+    pub async fn agent_service_register(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/register", AGENT_SERVICE_BASE_URL);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("agent_service_register", "/v1/agent/service/register");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
@@ -72,28 +108,79 @@ impl Runner {
         service_id: &str,
     ) -> Result<Response<Body>, Error> {
         let path = format!("{}/deregister/{}", AGENT_SERVICE_BASE_URL, service_id);
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new(
+            "agent_service_deregister",
+            "/v1/agent/service/deregister/{service_id}",
+        );
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn agent_service_maintenance(
         &self,
         service_id: &str,
+        enable: bool,
+        reason: Option<&str>,
     ) -> Result<Response<Body>, Error> {
-        let path = format!("{}/maintentance/{}", AGENT_SERVICE_BASE_URL, service_id);
-        let uri = self.build_uri(&path).await;
+        let mut query = vec![("enable", if enable { "true" } else { "false" })];
+
+        if let Some(reason) = reason {
+            query.push(("reason", reason));
+        }
+
+        let path = format!("{}/maintenance/{}", AGENT_SERVICE_BASE_URL, service_id);
+        let uri = self.build_uri_with_query(&path, &query, None).await?;
         let method = Method::PUT;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new(
+            "agent_service_maintenance",
+            "/v1/agent/service/maintenance/{service_id}",
+        );
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn agent_service_register_typed(
+        &self,
+        registration: &AgentServiceRegistration,
+    ) -> Result<(), Error> {
+        let payload = self.encode(registration)?;
+        self.agent_service_register(payload).await?;
+        Ok(())
+    }
+
+    pub async fn agent_services_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(HashMap<String, AgentService>, QueryMeta), Error> {
+        let (response, meta) = self.agent_services(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn agent_service_configuration_typed(
+        &self,
+        service_id: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(AgentService, QueryMeta), Error> {
+        let (response, meta) = self
+            .agent_service_configuration(service_id, options)
+            .await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,16 +188,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_services() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/services")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_services().await?;
+            .create_async()
+            .await;
+        test_runner.agent_services(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -118,17 +206,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_service_configuration() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/service/test_service_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .agent_service_configuration("test_service_id")
+            .agent_service_configuration("test_service_id", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -137,17 +226,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_health_service_name() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/health/service/name/test_service_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .agent_health_service_name("test_service_name")
+            .agent_health_service_name("test_service_name", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -156,17 +246,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_health_service_id() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/agent/health/service/id/test_service_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .agent_health_service_id("test_service_id")
+            .agent_health_service_id("test_service_id", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -175,16 +266,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_service_register() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/service/register")
+            .match_body(r#"{"Name": "test_service"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.agent_service_register().await?;
+            .create_async()
+            .await;
+        test_runner
+            .agent_service_register(r#"{"Name": "test_service"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -192,15 +287,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_service_deregister() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/agent/service/deregister/test_service_id")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
             .agent_service_deregister("test_service_id")
             .await?;
@@ -211,17 +307,54 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn agent_service_maintenance() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
-            .mock("PUT", "/v1/agent/service/maintentance/test_service_id")
+            .mock("PUT", "/v1/agent/service/maintenance/test_service_id")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "enable".into(),
+                "false".into(),
+            ))
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
+        test_runner
+            .agent_service_maintenance("test_service_id", false, None)
+            .await?;
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn agent_service_register_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/agent/service/register")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "ID": "test_service_1",
+                "Name": "test_service",
+                "Port": 8000,
+                "EnableTagOverride": false
+            })))
+            .with_status(200)
+            .with_body("")
+            .create_async()
+            .await;
+        let test_registration = AgentServiceRegistration {
+            id: String::from("test_service_1"),
+            name: String::from("test_service"),
+            port: 8000,
+            ..AgentServiceRegistration::default()
+        };
         test_runner
-            .agent_service_maintenance("test_service_id")
+            .agent_service_register_typed(&test_registration)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -1,126 +1,277 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Idempotent, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{
+    CatalogNode, CatalogNodeServiceList, CatalogRegistration, CatalogService, Node,
+};
+
+#[cfg(feature = "typed")]
+use std::collections::HashMap;
 
 const CATALOG_BASE_URL: &str = "/v1/catalog";
 
 impl Runner {
-    pub async fn catalog_register(&self) -> Result<Response<Body>, Error> {
+    pub async fn catalog_register(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/register", CATALOG_BASE_URL);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("catalog_register", "/v1/catalog/register");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn catalog_deregister(&self) -> Result<Response<Body>, Error> {
+    pub async fn catalog_deregister(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/deregister", CATALOG_BASE_URL);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("catalog_deregister", "/v1/catalog/deregister");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn catalog_datacenters(&self) -> Result<Response<Body>, Error> {
+    pub async fn catalog_datacenters(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/datacenters", CATALOG_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_datacenters", "/v1/catalog/datacenters");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_nodes(&self) -> Result<Response<Body>, Error> {
+    pub async fn catalog_nodes(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/nodes", CATALOG_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_nodes", "/v1/catalog/nodes");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_services(&self) -> Result<Response<Body>, Error> {
+    pub async fn catalog_services(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/services", CATALOG_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_services", "/v1/catalog/services");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_service_nodes(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn catalog_service_nodes(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/service/{}", CATALOG_BASE_URL, service);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_service_nodes", "/v1/catalog/service/{service}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_connect_nodes(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn catalog_connect_nodes(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/connect/{}", CATALOG_BASE_URL, service);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_connect_nodes", "/v1/catalog/connect/{service}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_node(&self, node: &str) -> Result<Response<Body>, Error> {
+    pub async fn catalog_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/node/{}", CATALOG_BASE_URL, node);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_node", "/v1/catalog/node/{node}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_node_services(&self, node: &str) -> Result<Response<Body>, Error> {
+    pub async fn catalog_node_services(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/node-services/{}", CATALOG_BASE_URL, node);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("catalog_node_services", "/v1/catalog/node-services/{node}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn catalog_gateway_services(&self, gateway: &str) -> Result<Response<Body>, Error> {
+    pub async fn catalog_gateway_services(
+        &self,
+        gateway: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/gateway-services/{}", CATALOG_BASE_URL, gateway);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new(
+            "catalog_gateway_services",
+            "/v1/catalog/gateway-services/{gateway}",
+        );
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn catalog_register_typed(
+        &self,
+        registration: &CatalogRegistration,
+    ) -> Result<bool, Error> {
+        let payload = self.encode(registration)?;
+        let response = self.catalog_register(payload).await?;
+        let registered = self.decode(response).await?;
+        Ok(registered)
+    }
+
+    pub async fn catalog_datacenters_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<String>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_datacenters(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn catalog_nodes_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<Node>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_nodes(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn catalog_services_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(HashMap<String, Vec<String>>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_services(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn catalog_service_nodes_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CatalogService>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_service_nodes(service, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn catalog_connect_nodes_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CatalogService>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_connect_nodes(service, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn catalog_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<CatalogNode>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_node(node, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn catalog_node_services_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<CatalogNodeServiceList>, QueryMeta), Error> {
+        let (response, meta) = self.catalog_node_services(node, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mockito::Server;
+    use crate::WaitOptions;
+    use mockito::{Matcher, Server};
+    use std::time::Duration;
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_register() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/catalog/register")
+            .match_body(r#"{"Node": "test_node", "Address": "192.168.10.10"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_register().await?;
+            .create_async()
+            .await;
+        test_runner
+            .catalog_register(r#"{"Node": "test_node", "Address": "192.168.10.10"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -128,16 +279,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_deregister() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/catalog/deregister")
+            .match_body(r#"{"Node": "test_node"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_deregister().await?;
+            .create_async()
+            .await;
+        test_runner
+            .catalog_deregister(r#"{"Node": "test_node"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -145,16 +300,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_datacenters() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/datacenters")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_datacenters().await?;
+            .create_async()
+            .await;
+        test_runner.catalog_datacenters(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -162,16 +318,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_nodes() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/nodes")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_nodes().await?;
+            .create_async()
+            .await;
+        test_runner.catalog_nodes(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -179,34 +336,67 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_services() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/services")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_services().await?;
+            .create_async()
+            .await;
+        test_runner.catalog_services(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn catalog_services_blocking() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/catalog/services")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(String::from("index"), String::from("10")),
+                Matcher::UrlEncoded(String::from("wait"), String::from("5000ms")),
+            ]))
+            .with_status(200)
+            .with_header("user-agent", "sublight/0.1.0")
+            .with_header("x-consul-index", "12")
+            .with_header("x-consul-knownleader", "true")
+            .with_header("x-consul-lastcontact", "0")
+            .with_body("")
+            .create_async()
+            .await;
+        let mut test_wait = WaitOptions::new(10).wait(Duration::from_secs(5));
+        let test_options = QueryOptions::new().wait(test_wait);
+        let (_, test_meta) = test_runner.catalog_services(Some(&test_options)).await?;
+        mock.assert();
+        assert!(mock.matched());
+        assert_eq!(test_meta.last_index, 12);
+        assert!(test_meta.known_leader);
+        assert!(test_wait.advance(&test_meta));
+        assert_eq!(test_wait.index, 12);
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_service_nodes() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/service/test_catalog_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .catalog_service_nodes("test_catalog_service")
+            .catalog_service_nodes("test_catalog_service", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -215,17 +405,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_connect_nodes() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/connect/test_connect_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .catalog_connect_nodes("test_connect_service")
+            .catalog_connect_nodes("test_connect_service", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -234,16 +425,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_node() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/node/test_node")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_node("test_node").await?;
+            .create_async()
+            .await;
+        test_runner.catalog_node("test_node", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -251,16 +443,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_node_services() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/node-services/test_node")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_node_services("test_node").await?;
+            .create_async()
+            .await;
+        test_runner.catalog_node_services("test_node", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -268,18 +461,130 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn catalog_gateway_services() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/catalog/gateway-services/test_gateway")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.catalog_gateway_services("test_gateway").await?;
+            .create_async()
+            .await;
+        test_runner
+            .catalog_gateway_services("test_gateway", None)
+            .await?;
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn catalog_nodes_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/catalog/nodes")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"ID": "40e4a748-2192-161a-0510-9bf59fe950b5", "Node": "test_node", "Address": "192.168.10.10", "Datacenter": "dc1", "CreateIndex": 5, "ModifyIndex": 6}]"#)
+            .create_async()
+            .await;
+        let (test_nodes, _) = test_runner.catalog_nodes_typed(None).await?;
+        assert_eq!(test_nodes.len(), 1);
+        assert_eq!(test_nodes[0].node, "test_node");
+        assert_eq!(test_nodes[0].address, "192.168.10.10");
+        assert_eq!(test_nodes[0].modify_index, 6);
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn catalog_register_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/catalog/register")
+            .match_body(Matcher::Json(serde_json::json!({
+                "Node": "test_node",
+                "Address": "192.168.10.10",
+                "SkipNodeUpdate": false
+            })))
+            .with_status(200)
+            .with_body("true")
+            .create_async()
+            .await;
+        let test_registration = CatalogRegistration {
+            node: String::from("test_node"),
+            address: String::from("192.168.10.10"),
+            ..CatalogRegistration::default()
+        };
+        let test_registered = test_runner
+            .catalog_register_typed(&test_registration)
+            .await?;
+        assert!(test_registered);
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn catalog_node_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/catalog/node/test_node")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"Node": {"Node": "test_node", "Address": "192.168.10.10"}, "Services": {"test_service": {"ID": "test_service", "Service": "test_service", "Port": 8000}}}"#)
+            .create_async()
+            .await;
+        let test_missing = test_server
+            .mock("GET", "/v1/catalog/node/test_missing")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("null")
+            .create_async()
+            .await;
+        let (test_node, _) = test_runner.catalog_node_typed("test_node", None).await?;
+        let test_node = test_node.unwrap_or_default();
+        assert_eq!(test_node.node.unwrap_or_default().address, "192.168.10.10");
+        assert_eq!(test_node.services["test_service"].port, 8000);
+        let (test_node, _) = test_runner.catalog_node_typed("test_missing", None).await?;
+        assert!(test_node.is_none());
+        mock.assert();
+        test_missing.assert();
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn catalog_node_services_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/catalog/node-services/test_node")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"Node": {"Node": "test_node"}, "Services": [{"ID": "test_service", "Service": "test_service", "Port": 8000}]}"#)
+            .create_async()
+            .await;
+        let (test_list, _) = test_runner
+            .catalog_node_services_typed("test_node", None)
+            .await?;
+        let test_list = test_list.unwrap_or_default();
+        assert_eq!(test_list.node.unwrap_or_default().node, "test_node");
+        assert_eq!(test_list.services.len(), 1);
+        assert_eq!(test_list.services[0].id, "test_service");
+        mock.assert();
+        Ok(())
+    }
 }
@@ -1,49 +1,77 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use serde::Serialize;
 
 const CONFIG_BASE_URL: &str = "/v1/config";
 
 impl Runner {
-    pub async fn config_apply(&self) -> Result<Response<Body>, Error> {
+    pub async fn config_apply(&self, payload: impl Into<Body>) -> Result<Response<Body>, Error> {
         let path = CONFIG_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("config_apply", "/v1/config");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn config_get(&self, kind: &str, name: &str) -> Result<Response<Body>, Error> {
+    pub async fn config_get(
+        &self,
+        kind: &str,
+        name: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}/{}", CONFIG_BASE_URL, kind, name);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("config_get", "/v1/config/{kind}/{name}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn config_list(&self, kind: &str) -> Result<Response<Body>, Error> {
+    pub async fn config_list(
+        &self,
+        kind: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/{}", CONFIG_BASE_URL, kind);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("config_list", "/v1/config/{kind}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
     pub async fn config_delete(&self, kind: &str, name: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/{}/{}", CONFIG_BASE_URL, kind, name);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("config_delete", "/v1/config/{kind}/{name}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn config_apply_typed<T: Serialize>(&self, entry: &T) -> Result<bool, Error> {
+        let payload = self.encode(entry)?;
+        let response = self.config_apply(payload).await?;
+        let applied = self.decode(response).await?;
+        Ok(applied)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,16 +79,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn config_apply() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/config")
+            .match_body(r#"{"Kind": "service-defaults", "Name": "test_service"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.config_apply().await?;
+            .create_async()
+            .await;
+        test_runner
+            .config_apply(r#"{"Kind": "service-defaults", "Name": "test_service"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -68,17 +100,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn config_get() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/config/test_config_kind/test_config_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
-            .config_get("test_config_kind", "test_config_name")
+            .config_get("test_config_kind", "test_config_name", None)
             .await?;
         mock.assert();
         assert!(mock.matched());
@@ -87,16 +120,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn config_list() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/config/test_config_kind")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.config_list("test_config_kind").await?;
+            .create_async()
+            .await;
+        test_runner.config_list("test_config_kind", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -104,15 +138,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn config_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/config/test_config_kind/test_config_name")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner
             .config_delete("test_config_kind", "test_config_name")
             .await?;
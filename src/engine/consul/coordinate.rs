@@ -1,49 +1,100 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::CoordinateEntry;
 
 const COORDINATE_BASE_URL: &str = "/v1/coordinate";
 
 impl Runner {
-    pub async fn coordinate_datacenters(&self) -> Result<Response<Body>, Error> {
+    pub async fn coordinate_datacenters(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/datacenters", COORDINATE_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("coordinate_datacenters", "/v1/coordinate/datacenters");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn coordinate_nodes(&self) -> Result<Response<Body>, Error> {
+    pub async fn coordinate_nodes(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/nodes", COORDINATE_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("coordinate_nodes", "/v1/coordinate/nodes");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn coordinate_node(&self, node: &str) -> Result<Response<Body>, Error> {
+    pub async fn coordinate_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/node/{}", COORDINATE_BASE_URL, node);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("coordinate_node", "/v1/coordinate/node/{node}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn coordinate_update(&self) -> Result<Response<Body>, Error> {
+    pub async fn coordinate_update(
+        &self,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/update", COORDINATE_BASE_URL);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("coordinate_update", "/v1/coordinate/update");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn coordinate_nodes_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CoordinateEntry>, QueryMeta), Error> {
+        let (response, meta) = self.coordinate_nodes(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn coordinate_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<CoordinateEntry>, QueryMeta), Error> {
+        let (response, meta) = self.coordinate_node(node, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn coordinate_update_typed(&self, entry: &CoordinateEntry) -> Result<(), Error> {
+        let payload = self.encode(entry)?;
+        self.coordinate_update(payload).await?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,16 +102,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn coordinate_datacenters() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/coordinate/datacenters")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.coordinate_datacenters().await?;
+            .create_async()
+            .await;
+        test_runner.coordinate_datacenters(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -68,16 +120,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn coordinate_nodes() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/coordinate/nodes")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.coordinate_nodes().await?;
+            .create_async()
+            .await;
+        test_runner.coordinate_nodes(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -85,16 +138,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn coordinate_node() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/coordinate/node/test_node")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.coordinate_node("test_node").await?;
+            .create_async()
+            .await;
+        test_runner.coordinate_node("test_node", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -102,16 +156,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn coordinate_update() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/coordinate/update")
+            .match_body(r#"{"Node": "test_node"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.coordinate_update().await?;
+            .create_async()
+            .await;
+        test_runner
+            .coordinate_update(r#"{"Node": "test_node"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -1,26 +1,61 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::UserEvent;
 
 const EVENT_BASE_URL: &str = "/v1/event";
 
 impl Runner {
-    pub async fn event_fire(&self, name: &str) -> Result<Response<Body>, Error> {
+    pub async fn event_fire(
+        &self,
+        name: &str,
+        payload: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = format!("{}/fire/{}", EVENT_BASE_URL, name);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("event_fire", "/v1/event/fire/{name}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn event_list(&self) -> Result<Response<Body>, Error> {
+    pub async fn event_list(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/list", EVENT_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("event_list", "/v1/event/list");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn event_fire_typed(
+        &self,
+        name: &str,
+        payload: impl Into<Body>,
+    ) -> Result<UserEvent, Error> {
+        let response = self.event_fire(name, payload).await?;
+        let fired = self.decode(response).await?;
+        Ok(fired)
+    }
+
+    pub async fn event_list_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<UserEvent>, QueryMeta), Error> {
+        let (response, meta) = self.event_list(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
@@ -31,16 +66,18 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn event_fire() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/event/fire/test_event")
+            .match_body("test_payload")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.event_fire("test_event").await?;
+            .create_async()
+            .await;
+        test_runner.event_fire("test_event", "test_payload").await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -48,16 +85,39 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn event_list() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/event/list")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.event_list().await?;
+            .create_async()
+            .await;
+        test_runner.event_list(None).await?;
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn event_list_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/event/list")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"ID": "test_event_id", "Name": "deploy", "Payload": null, "Version": 1, "LTime": 19}]"#)
+            .create_async()
+            .await;
+        let (test_events, _) = test_runner.event_list_typed(None).await?;
+        assert_eq!(test_events.len(), 1);
+        assert_eq!(test_events[0].name, "deploy");
+        assert_eq!(test_events[0].ltime, 19);
         mock.assert();
         assert!(mock.matched());
         Ok(())
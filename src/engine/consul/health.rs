@@ -1,86 +1,189 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{HealthCheck, ServiceEntry};
 
 const HEALTH_BASE_URL: &str = "/v1/health";
 
 impl Runner {
-    pub async fn health_node(&self, node: &str) -> Result<Response<Body>, Error> {
+    pub async fn health_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/node/{}", HEALTH_BASE_URL, node);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("health_node", "/v1/health/node/{node}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn health_checks(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn health_checks(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/checks/{}", HEALTH_BASE_URL, service);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("health_checks", "/v1/health/checks/{service}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn health_service(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn health_service(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/service/{}", HEALTH_BASE_URL, service);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("health_service", "/v1/health/service/{service}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn health_connect(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn health_connect(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/connect/{}", HEALTH_BASE_URL, service);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("health_connect", "/v1/health/connect/{service}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn health_ingress(&self, service: &str) -> Result<Response<Body>, Error> {
+    pub async fn health_ingress(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/ingress/{}", HEALTH_BASE_URL, service);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("health_ingress", "/v1/health/ingress/{service}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn health_state(&self, state: &str) -> Result<Response<Body>, Error> {
+    pub async fn health_state(
+        &self,
+        state: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/state/{}", HEALTH_BASE_URL, state);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("health_state", "/v1/health/state/{state}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn health_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<HealthCheck>, QueryMeta), Error> {
+        let (response, meta) = self.health_node(node, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn health_checks_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<HealthCheck>, QueryMeta), Error> {
+        let (response, meta) = self.health_checks(service, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn health_service_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ServiceEntry>, QueryMeta), Error> {
+        let (response, meta) = self.health_service(service, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn health_connect_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ServiceEntry>, QueryMeta), Error> {
+        let (response, meta) = self.health_connect(service, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn health_ingress_typed(
+        &self,
+        service: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<ServiceEntry>, QueryMeta), Error> {
+        let (response, meta) = self.health_ingress(service, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn health_state_typed(
+        &self,
+        state: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<HealthCheck>, QueryMeta), Error> {
+        let (response, meta) = self.health_state(state, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mockito::Server;
+    use mockito::{Matcher, Server};
 
     #[tokio::test(flavor = "multi_thread")]
     async fn health_node() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/health/node/test_node")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.health_node("test_node").await?;
+            .create_async()
+            .await;
+        test_runner.health_node("test_node", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -88,16 +191,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn health_checks() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/health/checks/test_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.health_checks("test_service").await?;
+            .create_async()
+            .await;
+        test_runner.health_checks("test_service", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -105,16 +209,48 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn health_service() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/health/service/test_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.health_service("test_service").await?;
+            .create_async()
+            .await;
+        test_runner.health_service("test_service", None).await?;
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn health_service_options() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/health/service/test_service")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(String::from("dc"), String::from("test_dc")),
+                Matcher::UrlEncoded(String::from("tag"), String::from("test_tag")),
+                Matcher::UrlEncoded(String::from("near"), String::from("_agent")),
+            ]))
+            .match_header("x-consul-token", "test_token")
+            .with_status(200)
+            .with_header("user-agent", "sublight/0.1.0")
+            .with_body("")
+            .create_async()
+            .await;
+        let test_options = QueryOptions::new()
+            .datacenter("test_dc")
+            .tag("test_tag")
+            .near("_agent")
+            .token("test_token");
+        test_runner
+            .health_service("test_service", Some(&test_options))
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -122,16 +258,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn health_connect() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/health/connect/test_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.health_connect("test_service").await?;
+            .create_async()
+            .await;
+        test_runner.health_connect("test_service", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -139,16 +276,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn health_ingress() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/health/ingress/test_service")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.health_ingress("test_service").await?;
+            .create_async()
+            .await;
+        test_runner.health_ingress("test_service", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -156,16 +294,42 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn health_state() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/health/state/test_state")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.health_state("test_state").await?;
+            .create_async()
+            .await;
+        test_runner.health_state("test_state", None).await?;
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn health_service_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/health/service/test_service")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"Node": {"Node": "test_node"}, "Service": {"ID": "test_service", "Service": "test_service", "Port": 8000}, "Checks": [{"CheckID": "serfHealth", "Status": "passing"}]}]"#)
+            .create_async()
+            .await;
+        let (test_entries, _) = test_runner
+            .health_service_typed("test_service", None)
+            .await?;
+        assert_eq!(test_entries.len(), 1);
+        assert_eq!(test_entries[0].node.node, "test_node");
+        assert_eq!(test_entries[0].service.port, 8000);
+        assert_eq!(test_entries[0].checks[0].status, "passing");
         mock.assert();
         assert!(mock.matched());
         Ok(())
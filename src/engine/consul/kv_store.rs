@@ -1,92 +1,633 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::query::AllowNotFound;
+use crate::{
+    Body, Bytes, Endpoint, Error, Idempotent, Method, QueryMeta, QueryOptions, Response, Runner,
+};
+
+use hyper::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub mod export;
+pub mod watch;
 
 const KV_STORE_BASE_URL: &str = "/v1/kv";
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct KVPair {
+    pub key: String,
+    pub create_index: u64,
+    pub modify_index: u64,
+    pub lock_index: u64,
+    pub flags: u64,
+    #[serde(with = "base64_value")]
+    pub value: Option<Bytes>,
+    pub session: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+}
+
+impl KVPair {
+    pub fn value_str(&self) -> Result<Option<&str>, Error> {
+        match &self.value {
+            Some(value) => Ok(Some(std::str::from_utf8(value).map_err(decode_error)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn value_json<T: DeserializeOwned>(&self) -> Result<Option<T>, Error> {
+        match &self.value {
+            Some(value) => Ok(Some(serde_json::from_slice(value)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KVPutOptions {
+    pub flags: Option<u64>,
+    pub cas: Option<u64>,
+    pub acquire: Option<String>,
+    pub release: Option<String>,
+}
+
+impl KVPutOptions {
+    pub fn new() -> KVPutOptions {
+        KVPutOptions::default()
+    }
+
+    pub fn flags(mut self, flags: u64) -> KVPutOptions {
+        self.flags = Some(flags);
+        self
+    }
+
+    pub fn cas(mut self, cas: u64) -> KVPutOptions {
+        self.cas = Some(cas);
+        self
+    }
+
+    pub fn acquire(mut self, session: impl Into<String>) -> KVPutOptions {
+        self.acquire = Some(session.into());
+        self
+    }
+
+    pub fn release(mut self, session: impl Into<String>) -> KVPutOptions {
+        self.release = Some(session.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KVDeleteOptions {
+    pub recurse: bool,
+    pub cas: Option<u64>,
+}
+
+impl KVDeleteOptions {
+    pub fn new() -> KVDeleteOptions {
+        KVDeleteOptions::default()
+    }
+
+    pub fn recurse(mut self, recurse: bool) -> KVDeleteOptions {
+        self.recurse = recurse;
+        self
+    }
+
+    pub fn cas(mut self, cas: u64) -> KVDeleteOptions {
+        self.cas = Some(cas);
+        self
+    }
+}
+
 impl Runner {
-    pub async fn key_get(&self, key: &str) -> Result<Response<Body>, Error> {
-        let path = format!("{}/{}", KV_STORE_BASE_URL, key);
+    pub async fn key_get(
+        &self,
+        key: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<KVPair>, QueryMeta), Error> {
+        let endpoint = Endpoint::new("key_get", "/v1/kv/{key}");
+        let (response, meta) = self.key_read(&endpoint, key, &[], options).await?;
+        let pair = match response {
+            Some(response) => {
+                let pairs: Vec<KVPair> = self.decode(response).await?;
+                pairs.into_iter().next()
+            }
+            None => None,
+        };
+        Ok((pair, meta))
+    }
+
+    pub async fn key_get_raw(
+        &self,
+        key: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<Bytes>, QueryMeta), Error> {
+        let query = [("raw", "true")];
+        let endpoint = Endpoint::new("key_get_raw", "/v1/kv/{key}");
+        let (response, meta) = self.key_read(&endpoint, key, &query, options).await?;
+        let value = match response {
+            Some(response) => Some(hyper::body::to_bytes(response.into_body()).await?),
+            None => None,
+        };
+        Ok((value, meta))
+    }
+
+    pub async fn key_list(
+        &self,
+        prefix: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<KVPair>, QueryMeta), Error> {
+        let query = [("recurse", "true")];
+        let endpoint = Endpoint::new("key_list", "/v1/kv/{prefix}");
+        let (response, meta) = self.key_read(&endpoint, prefix, &query, options).await?;
+        let pairs = match response {
+            Some(response) => self.decode(response).await?,
+            None => Vec::new(),
+        };
+        Ok((pairs, meta))
+    }
+
+    pub async fn key_keys(
+        &self,
+        prefix: &str,
+        separator: Option<&str>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<String>, QueryMeta), Error> {
+        let mut query = vec![("keys", "true")];
+
+        if let Some(separator) = separator {
+            query.push(("separator", separator));
+        }
+
+        let endpoint = Endpoint::new("key_keys", "/v1/kv/{prefix}");
+        let (response, meta) = self.key_read(&endpoint, prefix, &query, options).await?;
+        let keys = match response {
+            Some(response) => self.decode(response).await?,
+            None => Vec::new(),
+        };
+        Ok((keys, meta))
+    }
+
+    async fn key_read(
+        &self,
+        endpoint: &Endpoint<'_>,
+        key: &str,
+        query: &[(&str, &str)],
+        options: Option<&QueryOptions>,
+    ) -> Result<(Option<Response<Body>>, QueryMeta), Error> {
+        let path = key_path(key);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri_with_query(&path, query, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let mut request = self.build_request(method, uri, body, options).await?;
+        request.extensions_mut().insert(AllowNotFound);
+        let response = self.execute(endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok((None, meta)),
+            _ => Ok((Some(response), meta)),
+        }
     }
 
-    pub async fn key_put(&self, key: &str) -> Result<Response<Body>, Error> {
-        let path = format!("{}/{}", KV_STORE_BASE_URL, key);
+    pub async fn key_put(
+        &self,
+        key: &str,
+        value: impl Into<Body>,
+        kv: &KVPutOptions,
+    ) -> Result<bool, Error> {
+        let flags = kv.flags.map(|flags| flags.to_string());
+        let cas = kv.cas.map(|cas| cas.to_string());
+        let mut query = Vec::with_capacity(4);
+
+        if let Some(flags) = &flags {
+            query.push(("flags", flags.as_str()));
+        }
+
+        if let Some(cas) = &cas {
+            query.push(("cas", cas.as_str()));
+        }
+
+        if let Some(acquire) = &kv.acquire {
+            query.push(("acquire", acquire.as_str()));
+        }
+
+        if let Some(release) = &kv.release {
+            query.push(("release", release.as_str()));
+        }
+
+        let path = key_path(key);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let uri = self.build_uri_with_query(&path, &query, None).await?;
+        let body = value.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("key_put", "/v1/kv/{key}");
+        let response = self.execute(&endpoint, request).await?;
+        decode_bool(response).await
     }
 
-    pub async fn key_delete(&self, key: &str) -> Result<Response<Body>, Error> {
-        let path = format!("{}/{}", KV_STORE_BASE_URL, key);
+    pub async fn key_delete(&self, key: &str, kv: &KVDeleteOptions) -> Result<bool, Error> {
+        let cas = kv.cas.map(|cas| cas.to_string());
+        let mut query = Vec::with_capacity(2);
+
+        if kv.recurse {
+            query.push(("recurse", "true"));
+        }
+
+        if let Some(cas) = &cas {
+            query.push(("cas", cas.as_str()));
+        }
+
+        let path = key_path(key);
         let method = Method::DELETE;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri_with_query(&path, &query, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let mut request = self.build_request(method, uri, body, None).await?;
+
+        if cas.is_none() {
+            request.extensions_mut().insert(Idempotent);
+        }
+
+        let endpoint = Endpoint::new("key_delete", "/v1/kv/{key}");
+        let response = self.execute(&endpoint, request).await?;
+        decode_bool(response).await
+    }
+}
+
+fn key_path(key: &str) -> String {
+    let mut path = format!("{}/", KV_STORE_BASE_URL);
+
+    for byte in key.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                path.push(char::from(byte))
+            }
+            _ => path.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    path
+}
+
+async fn decode_bool(response: Response<Body>) -> Result<bool, Error> {
+    let bytes = hyper::body::to_bytes(response.into_body()).await?;
+
+    match String::from_utf8_lossy(&bytes).trim() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        other => Err(decode_error(format!(
+            "expected a boolean response, got {:?}",
+            other
+        ))),
+    }
+}
+
+fn decode_error(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+    Error::Decode(error.into())
+}
+
+pub(crate) mod base64_value {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use hyper::body::Bytes;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &Option<Bytes>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_str(&STANDARD.encode(value)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Bytes>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(encoded) => STANDARD
+                .decode(encoded)
+                .map(|decoded| Some(Bytes::from(decoded)))
+                .map_err(serde::de::Error::custom),
+            None => Ok(None),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mockito::Server;
+    use mockito::{Matcher, Server};
+    use std::collections::HashMap;
+
+    #[test]
+    fn kv_pair() -> Result<(), Error> {
+        let test_json = r#"{"Key": "test_key", "CreateIndex": 100, "ModifyIndex": 200, "LockIndex": 1, "Flags": 42, "Value": "eyJlbmFibGVkIjp0cnVlfQ==", "Session": "test_session"}"#;
+        let test_pair: KVPair = serde_json::from_str(test_json)?;
+        assert_eq!(test_pair.key, "test_key");
+        assert_eq!(test_pair.create_index, 100);
+        assert_eq!(test_pair.modify_index, 200);
+        assert_eq!(test_pair.lock_index, 1);
+        assert_eq!(test_pair.flags, 42);
+        assert_eq!(test_pair.session.as_deref(), Some("test_session"));
+        assert_eq!(test_pair.value_str()?, Some("{\"enabled\":true}"));
+        let test_value: Option<HashMap<String, bool>> = test_pair.value_json()?;
+        assert!(test_value.unwrap()["enabled"]);
+        let test_encoded = serde_json::to_value(&test_pair)?;
+        assert_eq!(test_encoded["Value"], "eyJlbmFibGVkIjp0cnVlfQ==");
+        let test_pair: KVPair = serde_json::from_str(r#"{"Key": "test_key", "Value": null}"#)?;
+        assert_eq!(test_pair.value, None);
+        assert_eq!(test_pair.value_str()?, None);
+        let test_pair = KVPair {
+            value: Some(Bytes::from_static(&[0xff, 0xfe])),
+            ..KVPair::default()
+        };
+        assert!(matches!(test_pair.value_str(), Err(Error::Decode(_))));
+        assert!(serde_json::from_str::<KVPair>(r#"{"Value": "!"}"#).is_err());
+        Ok(())
+    }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn key_get() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::Missing)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
-            .with_body("")
-            .create();
-        test_runner.key_get("test_key").await?;
+            .with_header("x-consul-index", "200")
+            .with_body(r#"[{"Key": "test_key", "CreateIndex": 100, "ModifyIndex": 200, "LockIndex": 0, "Flags": 0, "Value": "dGVzdA==", "Session": null}]"#)
+            .create_async()
+            .await;
+        let (test_pair, test_meta) = test_runner.key_get("test_key", None).await?;
         mock.assert();
-        assert!(mock.matched());
+        let test_pair = test_pair.unwrap();
+        assert_eq!(test_pair.key, "test_key");
+        assert_eq!(test_pair.value.as_deref(), Some(&b"test"[..]));
+        assert_eq!(test_pair.value_str()?, Some("test"));
+        assert!(test_pair.session.is_none());
+        assert_eq!(test_meta.last_index, 200);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_get_not_found() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .with_status(404)
+            .with_header("x-consul-index", "42")
+            .create_async()
+            .await;
+        let (test_pair, test_meta) = test_runner.key_get("test_key", None).await?;
+        mock.assert();
+        assert!(test_pair.is_none());
+        assert_eq!(test_meta.last_index, 42);
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .with_status(403)
+            .create_async()
+            .await;
+        let test_error = test_runner.key_get("test_key", None).await.unwrap_err();
+        mock.assert();
+        assert_eq!(test_error.status(), Some(StatusCode::FORBIDDEN));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_get_raw() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query("raw=true")
+            .with_status(200)
+            .with_body("test_value")
+            .create_async()
+            .await;
+        let (test_value, _) = test_runner.key_get_raw("test_key", None).await?;
+        mock.assert();
+        assert_eq!(test_value.as_deref(), Some(&b"test_value"[..]));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_list() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_prefix/")
+            .match_query("recurse=true")
+            .with_status(200)
+            .with_body(r#"[{"Key": "test_prefix/one", "Value": "b25l"}, {"Key": "test_prefix/two", "Value": null}]"#)
+            .create_async()
+            .await;
+        let (test_pairs, _) = test_runner.key_list("test_prefix/", None).await?;
+        mock.assert();
+        assert_eq!(test_pairs.len(), 2);
+        assert_eq!(test_pairs[0].value_str()?, Some("one"));
+        assert_eq!(test_pairs[1].key, "test_prefix/two");
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_missing/")
+            .match_query("recurse=true")
+            .with_status(404)
+            .create_async()
+            .await;
+        let (test_pairs, _) = test_runner.key_list("test_missing/", None).await?;
+        mock.assert();
+        assert!(test_pairs.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_keys() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_prefix/")
+            .match_query("keys=true&separator=%2F")
+            .with_status(200)
+            .with_body(r#"["test_prefix/one", "test_prefix/nested/"]"#)
+            .create_async()
+            .await;
+        let (test_keys, _) = test_runner
+            .key_keys("test_prefix/", Some("/"), None)
+            .await?;
+        mock.assert();
+        assert_eq!(test_keys, ["test_prefix/one", "test_prefix/nested/"]);
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn key_put() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/kv/test_key")
+            .match_query(Matcher::Missing)
+            .match_body("test_value")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
+            .with_body("true")
+            .create_async()
+            .await;
+        let test_stored = test_runner
+            .key_put("test_key", "test_value", &KVPutOptions::new())
+            .await?;
+        mock.assert();
+        assert!(test_stored);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_put_options() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/kv/test_key")
+            .match_query("flags=42&cas=100&acquire=test_session&release=test_session")
+            .match_body("test_value")
+            .with_status(200)
+            .with_body("false\n")
+            .create_async()
+            .await;
+        let test_kv = KVPutOptions::new()
+            .flags(42)
+            .cas(100)
+            .acquire("test_session")
+            .release("test_session");
+        let test_stored = test_runner
+            .key_put("test_key", "test_value", &test_kv)
+            .await?;
+        mock.assert();
+        assert!(!test_stored);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_put_invalid() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/kv/test_key")
+            .with_status(200)
             .with_body("")
-            .create();
-        test_runner.key_put("test_key").await?;
+            .create_async()
+            .await;
+        let test_error = test_runner
+            .key_put("test_key", "test_value", &KVPutOptions::new())
+            .await
+            .unwrap_err();
         mock.assert();
-        assert!(mock.matched());
+        assert!(matches!(test_error, Error::Decode(_)));
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn key_delete() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("DELETE", "/v1/kv/test_key")
+            .match_query(Matcher::Missing)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
-            .with_body("")
-            .create();
-        test_runner.key_delete("test_key").await?;
+            .with_body("true")
+            .create_async()
+            .await;
+        let test_deleted = test_runner
+            .key_delete("test_key", &KVDeleteOptions::new())
+            .await?;
+        mock.assert();
+        assert!(test_deleted);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_delete_options() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("DELETE", "/v1/kv/test_prefix")
+            .match_query("recurse=true&cas=100")
+            .with_status(200)
+            .with_body("false")
+            .create_async()
+            .await;
+        let test_kv = KVDeleteOptions::new().recurse(true).cas(100);
+        let test_deleted = test_runner.key_delete("test_prefix", &test_kv).await?;
+        mock.assert();
+        assert!(!test_deleted);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_delete_retry() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_retry = crate::RetryPolicy::new()
+            .base_delay(std::time::Duration::from_millis(1))
+            .idempotent_writes(true);
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .retry_policy(test_retry)
+            .build()?;
+        let mock = test_server
+            .mock("DELETE", "/v1/kv/test_key")
+            .match_query("cas=100")
+            .with_status(500)
+            .expect(1)
+            .create_async()
+            .await;
+        let test_kv = KVDeleteOptions::new().cas(100);
+        let test_result = test_runner.key_delete("test_key", &test_kv).await;
+        assert!(matches!(test_result, Err(Error::Status { status, .. }) if status == 500));
+        mock.assert();
+        let mock = test_server
+            .mock("DELETE", "/v1/kv/test_key")
+            .match_query(Matcher::Missing)
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+        let test_result = test_runner
+            .key_delete("test_key", &KVDeleteOptions::new())
+            .await;
+        assert!(test_result.is_err());
+        mock.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_path_encoding() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("DELETE", "/v1/kv/test%20dir/a%3Fcas%3D0%23b%25c")
+            .match_query(Matcher::Missing)
+            .with_body("true")
+            .create_async()
+            .await;
+        let test_deleted = test_runner
+            .key_delete("test dir/a?cas=0#b%c", &KVDeleteOptions::new())
+            .await?;
         mock.assert();
-        assert!(mock.matched());
+        assert!(test_deleted);
+        assert_eq!(key_path("test_app/ü"), "/v1/kv/test_app/%C3%BC");
         Ok(())
     }
 }
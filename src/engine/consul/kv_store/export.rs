@@ -0,0 +1,457 @@
+use crate::engine::consul::kv_store::{base64_value, KVPair};
+use crate::engine::consul::txn::{KVOperation, KVVerb, Txn, TxnOperation, MAX_TXN_OPERATIONS};
+use crate::{Bytes, Error, QueryOptions, Runner};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KVExportEntry {
+    pub key: String,
+    #[serde(default)]
+    pub flags: u64,
+    #[serde(
+        default,
+        serialize_with = "serialize_value",
+        deserialize_with = "deserialize_value"
+    )]
+    pub value: Bytes,
+}
+
+impl From<KVPair> for KVExportEntry {
+    fn from(pair: KVPair) -> KVExportEntry {
+        KVExportEntry {
+            key: pair.key,
+            flags: pair.flags,
+            value: pair.value.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportOptions {
+    pub prefix: String,
+    pub prune: bool,
+    pub dry_run: bool,
+}
+
+impl ImportOptions {
+    pub fn new() -> ImportOptions {
+        ImportOptions::default()
+    }
+
+    pub fn prefix(mut self, prefix: impl Into<String>) -> ImportOptions {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn prune(mut self, prune: bool) -> ImportOptions {
+        self.prune = prune;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> ImportOptions {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: usize,
+}
+
+impl ImportReport {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.deleted.is_empty()
+    }
+}
+
+impl Runner {
+    pub async fn kv_export(
+        &self,
+        prefix: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<Vec<KVExportEntry>, Error> {
+        let (pairs, _) = self.key_list(prefix, options).await?;
+        let entries = pairs.into_iter().map(KVExportEntry::from).collect();
+        Ok(entries)
+    }
+
+    pub async fn kv_import(
+        &self,
+        entries: &[KVExportEntry],
+        import: &ImportOptions,
+        options: Option<&QueryOptions>,
+    ) -> Result<ImportReport, Error> {
+        let mut imported = BTreeMap::new();
+
+        for entry in entries {
+            if !entry.key.starts_with(&import.prefix) {
+                return Err(Error::InvalidConfig(format!(
+                    "kv import entry {} is outside of prefix {}",
+                    entry.key, import.prefix,
+                )));
+            }
+
+            imported.insert(entry.key.as_str(), entry);
+        }
+
+        let (pairs, _) = self.key_list(&import.prefix, options).await?;
+        let current: BTreeMap<String, KVPair> = pairs
+            .into_iter()
+            .map(|pair| (pair.key.clone(), pair))
+            .collect();
+
+        let mut report = ImportReport::default();
+        let mut changes = Vec::new();
+
+        for (key, entry) in &imported {
+            let set = KVOperation::new(KVVerb::Set, *key)
+                .value(entry.value.clone())
+                .flags(entry.flags);
+
+            let operations = match current.get(*key) {
+                None => {
+                    report.added.push(key.to_string());
+                    vec![KVOperation::new(KVVerb::CheckNotExists, *key), set]
+                }
+                Some(pair)
+                    if pair.flags != entry.flags
+                        || pair.value.as_deref().unwrap_or_default() != entry.value =>
+                {
+                    report.changed.push(key.to_string());
+                    vec![KVOperation {
+                        verb: KVVerb::Cas,
+                        index: pair.modify_index,
+                        ..set
+                    }]
+                }
+                Some(_) => {
+                    report.unchanged += 1;
+                    continue;
+                }
+            };
+
+            changes.push((key.to_string(), operations));
+        }
+
+        if import.prune {
+            for (key, pair) in &current {
+                if !imported.contains_key(key.as_str()) {
+                    report.deleted.push(key.clone());
+                    let operation =
+                        KVOperation::new(KVVerb::DeleteCas, key.as_str()).index(pair.modify_index);
+                    changes.push((key.clone(), vec![operation]));
+                }
+            }
+        }
+
+        if import.dry_run {
+            return Ok(report);
+        }
+
+        let mut applied = Vec::new();
+        let mut batch = Txn::new();
+        let mut keys = Vec::new();
+
+        for (key, operations) in changes {
+            if batch.len() + operations.len() > MAX_TXN_OPERATIONS {
+                self.kv_import_batch(&batch, &mut keys, &mut applied, options)
+                    .await?;
+                batch = Txn::new();
+            }
+
+            batch = operations.into_iter().fold(batch, Txn::kv);
+            keys.push(key);
+        }
+
+        if !batch.is_empty() {
+            self.kv_import_batch(&batch, &mut keys, &mut applied, options)
+                .await?;
+        }
+
+        Ok(report)
+    }
+
+    async fn kv_import_batch(
+        &self,
+        batch: &Txn,
+        keys: &mut Vec<String>,
+        applied: &mut Vec<String>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(), Error> {
+        let response = match self.txn(batch, options).await {
+            Ok(response) => response,
+            Err(error) => {
+                return Err(Error::RolledBack {
+                    applied: std::mem::take(applied),
+                    errors: Vec::new(),
+                    source: Some(Box::new(error)),
+                });
+            }
+        };
+
+        if !response.is_committed() {
+            let errors = response
+                .errors
+                .into_iter()
+                .map(|error| {
+                    let key = match batch.operations().get(error.op_index) {
+                        Some(TxnOperation::KV(operation)) => operation.key.as_str(),
+                        _ => "",
+                    };
+
+                    format!("{}: {}", key, error.what)
+                })
+                .collect();
+
+            return Err(Error::RolledBack {
+                applied: std::mem::take(applied),
+                errors,
+                source: None,
+            });
+        }
+
+        applied.append(keys);
+        Ok(())
+    }
+}
+
+fn serialize_value<S: Serializer>(value: &Bytes, serializer: S) -> Result<S::Ok, S::Error> {
+    base64_value::serialize(&Some(value.clone()), serializer)
+}
+
+fn deserialize_value<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bytes, D::Error> {
+    Ok(base64_value::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    const TEST_CURRENT: &str = r#"[
+        {"Key": "test_app/same", "Flags": 0, "Value": "c2FtZQ==", "ModifyIndex": 11},
+        {"Key": "test_app/value", "Flags": 0, "Value": "b2xk", "ModifyIndex": 12},
+        {"Key": "test_app/flags", "Flags": 1, "Value": "ZmxhZ3M=", "ModifyIndex": 13},
+        {"Key": "test_app/stale", "Flags": 0, "Value": null, "ModifyIndex": 14}
+    ]"#;
+
+    fn test_entries() -> Vec<KVExportEntry> {
+        let test_json = json!([
+            {"key": "test_app/same", "flags": 0, "value": "c2FtZQ=="},
+            {"key": "test_app/value", "flags": 0, "value": "bmV3"},
+            {"key": "test_app/flags", "flags": 2, "value": "ZmxhZ3M="},
+            {"key": "test_app/added", "flags": 0, "value": ""},
+        ]);
+        serde_json::from_value(test_json).unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_export() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_app/")
+            .match_query("recurse=true")
+            .with_body(TEST_CURRENT)
+            .create_async()
+            .await;
+        let test_entries = test_runner.kv_export("test_app/", None).await?;
+        mock.assert();
+        assert_eq!(
+            serde_json::to_value(&test_entries)?,
+            json!([
+                {"key": "test_app/same", "flags": 0, "value": "c2FtZQ=="},
+                {"key": "test_app/value", "flags": 0, "value": "b2xk"},
+                {"key": "test_app/flags", "flags": 1, "value": "ZmxhZ3M="},
+                {"key": "test_app/stale", "flags": 0, "value": ""},
+            ]),
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_import_dry_run() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_app/")
+            .match_query("recurse=true")
+            .with_body(TEST_CURRENT)
+            .create_async()
+            .await;
+        let mock_txn = test_server
+            .mock("PUT", "/v1/txn")
+            .expect(0)
+            .create_async()
+            .await;
+        let test_import = ImportOptions::new()
+            .prefix("test_app/")
+            .prune(true)
+            .dry_run(true);
+        let test_report = test_runner
+            .kv_import(&test_entries(), &test_import, None)
+            .await?;
+        mock.assert();
+        mock_txn.assert();
+        assert_eq!(test_report.added, ["test_app/added"]);
+        assert_eq!(test_report.changed, ["test_app/flags", "test_app/value"]);
+        assert_eq!(test_report.deleted, ["test_app/stale"]);
+        assert_eq!(test_report.unchanged, 1);
+        let test_import = ImportOptions::new().prefix("test_other/");
+        let test_error = test_runner
+            .kv_import(&test_entries(), &test_import, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(test_error, Error::InvalidConfig(_)));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_import() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_app/")
+            .match_query("recurse=true")
+            .with_body(TEST_CURRENT)
+            .create_async()
+            .await;
+        let mock_txn = test_server
+            .mock("PUT", "/v1/txn")
+            .match_body(Matcher::Json(json!([
+                {"KV": {"Verb": "check-not-exists", "Key": "test_app/added"}},
+                {"KV": {"Verb": "set", "Key": "test_app/added", "Value": ""}},
+                {"KV": {"Verb": "cas", "Key": "test_app/flags", "Value": "ZmxhZ3M=", "Flags": 2, "Index": 13}},
+                {"KV": {"Verb": "cas", "Key": "test_app/value", "Value": "bmV3", "Index": 12}},
+                {"KV": {"Verb": "delete-cas", "Key": "test_app/stale", "Index": 14}},
+            ])))
+            .with_body(r#"{"Results": [], "Errors": null}"#)
+            .create_async()
+            .await;
+        let test_import = ImportOptions::new().prefix("test_app/").prune(true);
+        let test_report = test_runner
+            .kv_import(&test_entries(), &test_import, None)
+            .await?;
+        mock.assert();
+        mock_txn.assert();
+        assert_eq!(test_report.added.len(), 1);
+        assert_eq!(test_report.deleted.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_import_batches() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/")
+            .match_query("recurse=true")
+            .with_status(404)
+            .create_async()
+            .await;
+        let mock_txn = test_server
+            .mock("PUT", "/v1/txn")
+            .with_body(r#"{"Results": [], "Errors": null}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let mock_conflict = test_server
+            .mock("PUT", "/v1/txn")
+            .with_status(409)
+            .with_body(r#"{"Results": null, "Errors": [{"OpIndex": 3, "What": "test_conflict"}]}"#)
+            .create_async()
+            .await;
+        let test_entries: Vec<KVExportEntry> = (0..MAX_TXN_OPERATIONS * 2 + 1)
+            .map(|test_index| KVExportEntry {
+                key: format!("test_key_{:03}", test_index),
+                ..KVExportEntry::default()
+            })
+            .collect();
+        let test_error = test_runner
+            .kv_import(&test_entries, &ImportOptions::new(), None)
+            .await
+            .unwrap_err();
+        mock.assert();
+        mock_txn.assert();
+        mock_conflict.assert();
+        let (test_applied, test_errors) = match test_error {
+            Error::RolledBack {
+                applied,
+                errors,
+                source: None,
+            } => (applied, errors),
+            test_error => panic!("unexpected error {:?}", test_error),
+        };
+        let test_expected: Vec<String> = test_entries[..MAX_TXN_OPERATIONS]
+            .iter()
+            .map(|test_entry| test_entry.key.clone())
+            .collect();
+        assert_eq!(test_applied, test_expected);
+        assert_eq!(test_errors, ["test_key_065: test_conflict"]);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn kv_import_batch_failure() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/")
+            .match_query("recurse=true")
+            .with_status(404)
+            .create_async()
+            .await;
+        let mock_txn = test_server
+            .mock("PUT", "/v1/txn")
+            .with_body(r#"{"Results": [], "Errors": null}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let mock_unavailable = test_server
+            .mock("PUT", "/v1/txn")
+            .with_status(503)
+            .create_async()
+            .await;
+        let test_entries: Vec<KVExportEntry> = (0..MAX_TXN_OPERATIONS)
+            .map(|test_index| KVExportEntry {
+                key: format!("test_key_{:03}", test_index),
+                ..KVExportEntry::default()
+            })
+            .collect();
+        let test_error = test_runner
+            .kv_import(&test_entries, &ImportOptions::new(), None)
+            .await
+            .unwrap_err();
+        mock.assert();
+        mock_txn.assert();
+        mock_unavailable.assert();
+        let (test_applied, test_source) = match test_error {
+            Error::RolledBack {
+                applied,
+                errors,
+                source: Some(source),
+            } if errors.is_empty() => (applied, source),
+            test_error => panic!("unexpected error {:?}", test_error),
+        };
+        assert_eq!(test_applied.len(), MAX_TXN_OPERATIONS / 2);
+        assert_eq!(test_applied[0], "test_key_000");
+        assert_eq!(
+            test_source.status(),
+            Some(hyper::StatusCode::SERVICE_UNAVAILABLE)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,389 @@
+use crate::engine::consul::kv_store::KVPair;
+use crate::{Error, QueryMeta, QueryOptions, RetryPolicy, Runner, WaitOptions};
+
+use futures_core::Stream;
+use tokio::time::{Instant, Sleep};
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_BASE_DELAY: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+type Fetching = Pin<Box<dyn Future<Output = Result<(Vec<KVPair>, QueryMeta), Error>> + Send>>;
+
+pub type KeyWatch = Watch<Option<KVPair>>;
+pub type PrefixWatch = Watch<Vec<KVPair>>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchOptions {
+    pub wait: Option<Duration>,
+    pub min_interval: Duration,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl WatchOptions {
+    pub fn new() -> WatchOptions {
+        WatchOptions::default()
+    }
+
+    pub fn wait(mut self, wait: Duration) -> WatchOptions {
+        self.wait = Some(wait);
+        self
+    }
+
+    pub fn min_interval(mut self, min_interval: Duration) -> WatchOptions {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> WatchOptions {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> WatchOptions {
+        self.max_delay = max_delay;
+        self
+    }
+}
+
+impl Default for WatchOptions {
+    fn default() -> WatchOptions {
+        WatchOptions {
+            wait: None,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+        }
+    }
+}
+
+enum State {
+    Fetching(Fetching),
+    Waiting(Pin<Box<Sleep>>),
+}
+
+pub struct Watch<T> {
+    runner: Runner,
+    key: String,
+    recurse: bool,
+    options: QueryOptions,
+    wait: WaitOptions,
+    min_interval: Duration,
+    backoff: RetryPolicy,
+    failures: u32,
+    started: Instant,
+    last: Option<Vec<(String, u64)>>,
+    state: State,
+    convert: fn(Vec<KVPair>) -> T,
+}
+
+impl Runner {
+    pub fn watch_key(
+        &self,
+        key: &str,
+        watch: &WatchOptions,
+        options: Option<&QueryOptions>,
+    ) -> KeyWatch {
+        Watch::new(self.clone(), key, false, watch, options, |pairs| {
+            pairs.into_iter().next()
+        })
+    }
+
+    pub fn watch_prefix(
+        &self,
+        prefix: &str,
+        watch: &WatchOptions,
+        options: Option<&QueryOptions>,
+    ) -> PrefixWatch {
+        Watch::new(self.clone(), prefix, true, watch, options, |pairs| pairs)
+    }
+}
+
+impl<T> Watch<T> {
+    fn new(
+        runner: Runner,
+        key: &str,
+        recurse: bool,
+        watch: &WatchOptions,
+        options: Option<&QueryOptions>,
+        convert: fn(Vec<KVPair>) -> T,
+    ) -> Watch<T> {
+        let mut wait = WaitOptions::new(0);
+
+        if let Some(duration) = watch.wait {
+            wait = wait.wait(duration);
+        }
+
+        let backoff = RetryPolicy::new()
+            .base_delay(watch.base_delay)
+            .max_delay(watch.max_delay);
+
+        let key = key.to_owned();
+        let options = options.cloned().unwrap_or_default();
+        let fetching = fetch(&runner, &key, recurse, &options, wait);
+
+        Watch {
+            runner,
+            key,
+            recurse,
+            options,
+            wait,
+            min_interval: watch.min_interval,
+            backoff,
+            failures: 0,
+            started: Instant::now(),
+            last: None,
+            state: State::Fetching(fetching),
+            convert,
+        }
+    }
+
+    pub async fn next_update(&mut self) -> Option<Result<T, Error>> {
+        std::future::poll_fn(|context| self.poll_update(context)).await
+    }
+
+    pub fn index(&self) -> u64 {
+        self.wait.index
+    }
+
+    fn fetch(&mut self) {
+        let fetching = fetch(
+            &self.runner,
+            &self.key,
+            self.recurse,
+            &self.options,
+            self.wait,
+        );
+
+        self.started = Instant::now();
+        self.state = State::Fetching(fetching);
+    }
+
+    fn pause(&mut self, until: Instant) {
+        self.state = State::Waiting(Box::pin(tokio::time::sleep_until(until)));
+    }
+
+    fn poll_update(&mut self, context: &mut Context<'_>) -> Poll<Option<Result<T, Error>>> {
+        loop {
+            match &mut self.state {
+                State::Waiting(sleep) => {
+                    ready!(sleep.as_mut().poll(context));
+                    self.fetch();
+                }
+                State::Fetching(fetching) => match ready!(fetching.as_mut().poll(context)) {
+                    Ok((pairs, meta)) => {
+                        self.failures = 0;
+                        self.wait.advance(&meta);
+                        self.pause(self.started + self.min_interval);
+
+                        let modified: Vec<(String, u64)> = pairs
+                            .iter()
+                            .map(|pair| (pair.key.clone(), pair.modify_index))
+                            .collect();
+
+                        if self.last.as_ref() != Some(&modified) {
+                            self.last = Some(modified);
+                            return Poll::Ready(Some(Ok((self.convert)(pairs))));
+                        }
+                    }
+                    Err(error) => {
+                        self.failures = self.failures.saturating_add(1);
+                        let delay = self.backoff.delay(self.failures);
+                        let until = (self.started + self.min_interval).max(Instant::now() + delay);
+                        self.pause(until);
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                },
+            }
+        }
+    }
+}
+
+impl<T> Stream for Watch<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_update(context)
+    }
+}
+
+fn fetch(
+    runner: &Runner,
+    key: &str,
+    recurse: bool,
+    options: &QueryOptions,
+    wait: WaitOptions,
+) -> Fetching {
+    let runner = runner.clone();
+    let key = key.to_owned();
+    let options = options.clone().wait(wait);
+
+    Box::pin(async move {
+        match recurse {
+            true => runner.key_list(&key, Some(&options)).await,
+            false => {
+                let (pair, meta) = runner.key_get(&key, Some(&options)).await?;
+                Ok((pair.into_iter().collect(), meta))
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    fn test_options() -> WatchOptions {
+        WatchOptions::new()
+            .wait(Duration::from_secs(5))
+            .min_interval(Duration::from_millis(10))
+            .base_delay(Duration::from_millis(10))
+            .max_delay(Duration::from_millis(20))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn watch_key() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock_initial = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::UrlEncoded("index".into(), "0".into()))
+            .with_header("x-consul-index", "10")
+            .with_body(r#"[{"Key": "test_key", "ModifyIndex": 10, "Value": "b25l"}]"#)
+            .create_async()
+            .await;
+        let mock_unchanged = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::UrlEncoded("index".into(), "10".into()))
+            .with_header("x-consul-index", "11")
+            .with_body(r#"[{"Key": "test_key", "ModifyIndex": 10, "Value": "b25l"}]"#)
+            .create_async()
+            .await;
+        let mock_changed = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::UrlEncoded("index".into(), "11".into()))
+            .with_header("x-consul-index", "12")
+            .with_body(r#"[{"Key": "test_key", "ModifyIndex": 12, "Value": "dHdv"}]"#)
+            .create_async()
+            .await;
+        let mock_deleted = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::UrlEncoded("index".into(), "12".into()))
+            .with_status(404)
+            .with_header("x-consul-index", "13")
+            .create_async()
+            .await;
+        let mut test_watch = test_runner.watch_key("test_key", &test_options(), None);
+        let test_pair = test_watch.next_update().await.unwrap()?.unwrap();
+        assert_eq!(test_pair.value_str()?, Some("one"));
+        let test_pair = test_watch.next_update().await.unwrap()?.unwrap();
+        assert_eq!(test_pair.value_str()?, Some("two"));
+        assert!(test_watch.next_update().await.unwrap()?.is_none());
+        assert_eq!(test_watch.index(), 13);
+        mock_initial.assert();
+        mock_unchanged.assert();
+        mock_changed.assert();
+        mock_deleted.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn watch_prefix() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock_initial = test_server
+            .mock("GET", "/v1/kv/test_prefix/")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("recurse".into(), "true".into()),
+                Matcher::UrlEncoded("index".into(), "0".into()),
+                Matcher::UrlEncoded("wait".into(), "5000ms".into()),
+            ]))
+            .with_header("x-consul-index", "20")
+            .with_body(r#"[{"Key": "test_prefix/one", "ModifyIndex": 15}, {"Key": "test_prefix/two", "ModifyIndex": 20}]"#)
+            .create_async()
+            .await;
+        let mock_reset = test_server
+            .mock("GET", "/v1/kv/test_prefix/")
+            .match_query(Matcher::UrlEncoded("index".into(), "20".into()))
+            .with_header("x-consul-index", "5")
+            .with_body(r#"[{"Key": "test_prefix/one", "ModifyIndex": 15}, {"Key": "test_prefix/two", "ModifyIndex": 20}]"#)
+            .create_async()
+            .await;
+        let mock_deleted = test_server
+            .mock("GET", "/v1/kv/test_prefix/")
+            .match_query(Matcher::UrlEncoded("index".into(), "0".into()))
+            .with_header("x-consul-index", "21")
+            .with_body(r#"[{"Key": "test_prefix/one", "ModifyIndex": 15}]"#)
+            .create_async()
+            .await;
+        let mut test_watch = test_runner.watch_prefix("test_prefix/", &test_options(), None);
+        let test_pairs = test_watch.next_update().await.unwrap()?;
+        assert_eq!(test_pairs.len(), 2);
+        mock_initial.assert();
+        let test_pairs = test_watch.next_update().await.unwrap()?;
+        assert_eq!(test_pairs.len(), 1);
+        mock_reset.assert();
+        mock_deleted.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn watch_spawn() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::Any)
+            .with_header("x-consul-index", "10")
+            .with_body(r#"[{"Key": "test_key", "ModifyIndex": 10, "Value": "b25l"}]"#)
+            .create_async()
+            .await;
+        let mut test_watch = test_runner.watch_key("test_key", &test_options(), None);
+        drop(test_runner);
+        let test_update = tokio::spawn(async move { test_watch.next_update().await })
+            .await
+            .unwrap();
+        let test_pair = test_update.unwrap()?.unwrap();
+        mock.assert();
+        assert_eq!(test_pair.value_str()?, Some("one"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn watch_backoff() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock_error = test_server
+            .mock("GET", "/v1/kv/test_key")
+            .match_query(Matcher::Any)
+            .with_status(500)
+            .expect(2)
+            .create_async()
+            .await;
+        let test_watch = WatchOptions::new()
+            .min_interval(Duration::from_millis(50))
+            .base_delay(Duration::from_millis(10));
+        let mut test_watch = test_runner.watch_key("test_key", &test_watch, None);
+        let test_started = Instant::now();
+        let test_error = test_watch.next_update().await.unwrap().unwrap_err();
+        assert_eq!(
+            test_error.status(),
+            Some(hyper::StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        assert!(test_watch.next_update().await.unwrap().is_err());
+        assert!(test_started.elapsed() >= Duration::from_millis(50));
+        mock_error.assert();
+        Ok(())
+    }
+}
@@ -0,0 +1,638 @@
+use serde::{Deserialize, Serialize};
+
+use std::collections::HashMap;
+
+pub use crate::engine::consul::kv_store::KVPair;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Node {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub node: String,
+    pub address: String,
+    pub datacenter: String,
+    pub tagged_addresses: Option<HashMap<String, String>>,
+    pub meta: Option<HashMap<String, String>>,
+    pub partition: Option<String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CatalogService {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub node: String,
+    pub address: String,
+    pub datacenter: String,
+    pub tagged_addresses: Option<HashMap<String, String>>,
+    pub node_meta: Option<HashMap<String, String>>,
+    #[serde(rename = "ServiceID")]
+    pub service_id: String,
+    pub service_name: String,
+    pub service_address: String,
+    pub service_tagged_addresses: Option<HashMap<String, ServiceAddress>>,
+    pub service_tags: Option<Vec<String>>,
+    pub service_meta: Option<HashMap<String, String>>,
+    pub service_port: u16,
+    pub service_weights: Option<Weights>,
+    pub service_enable_tag_override: bool,
+    pub service_kind: Option<String>,
+    pub namespace: Option<String>,
+    pub partition: Option<String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ServiceAddress {
+    #[serde(alias = "address")]
+    pub address: String,
+    #[serde(alias = "port")]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Weights {
+    pub passing: i32,
+    pub warning: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ServiceEntry {
+    pub node: Node,
+    pub service: AgentService,
+    pub checks: Vec<HealthCheck>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct HealthCheck {
+    pub node: String,
+    #[serde(rename = "CheckID")]
+    pub check_id: String,
+    pub name: String,
+    pub status: String,
+    pub notes: String,
+    pub output: String,
+    #[serde(rename = "ServiceID")]
+    pub service_id: String,
+    pub service_name: String,
+    pub service_tags: Option<Vec<String>>,
+    pub r#type: String,
+    pub namespace: Option<String>,
+    pub partition: Option<String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct AgentService {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub service: String,
+    pub kind: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub meta: Option<HashMap<String, String>>,
+    pub port: u16,
+    pub address: String,
+    pub tagged_addresses: Option<HashMap<String, ServiceAddress>>,
+    pub weights: Option<Weights>,
+    pub enable_tag_override: bool,
+    pub datacenter: Option<String>,
+    pub namespace: Option<String>,
+    pub partition: Option<String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CatalogNode {
+    pub node: Option<Node>,
+    pub services: HashMap<String, AgentService>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CatalogNodeServiceList {
+    pub node: Option<Node>,
+    pub services: Vec<AgentService>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct AgentMember {
+    pub name: String,
+    pub addr: String,
+    pub port: u16,
+    pub tags: Option<HashMap<String, String>>,
+    pub status: i32,
+    pub protocol_min: u8,
+    pub protocol_max: u8,
+    pub protocol_cur: u8,
+    pub delegate_min: u8,
+    pub delegate_max: u8,
+    pub delegate_cur: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SessionEntry {
+    #[serde(rename = "ID")]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub node: String,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub lock_delay: u64,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub behavior: String,
+    #[serde(rename = "TTL")]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub ttl: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_checks: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_checks: Option<Vec<ServiceCheck>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub create_index: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ServiceCheck {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub namespace: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLLink {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLServiceIdentity {
+    pub service_name: String,
+    pub datacenters: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLNodeIdentity {
+    pub node_name: String,
+    pub datacenter: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLToken {
+    #[serde(rename = "AccessorID")]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub accessor_id: String,
+    #[serde(rename = "SecretID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_id: Option<String>,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<ACLLink>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<ACLLink>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_identities: Option<Vec<ACLServiceIdentity>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_identities: Option<Vec<ACLNodeIdentity>>,
+    pub local: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub create_index: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLPolicy {
+    #[serde(rename = "ID")]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    pub rules: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datacenters: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub create_index: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLRole {
+    #[serde(rename = "ID")]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub policies: Option<Vec<ACLLink>>,
+    pub service_identities: Option<Vec<ACLServiceIdentity>>,
+    pub node_identities: Option<Vec<ACLNodeIdentity>>,
+    pub hash: Option<String>,
+    pub namespace: Option<String>,
+    pub partition: Option<String>,
+    pub create_index: u64,
+    pub modify_index: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ACLLoginParams {
+    pub auth_method: String,
+    pub bearer_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct UserEvent {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    pub payload: Option<String>,
+    pub node_filter: String,
+    pub service_filter: String,
+    pub tag_filter: String,
+    pub version: u32,
+    #[serde(rename = "LTime")]
+    pub ltime: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CatalogRegistration {
+    #[serde(rename = "ID", skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub node: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub datacenter: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagged_addresses: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_meta: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<AgentService>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<Vec<HealthCheck>>,
+    pub skip_node_update: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct AgentServiceRegistration {
+    #[serde(rename = "ID", skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "is_zero_port")]
+    pub port: u16,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tagged_addresses: Option<HashMap<String, ServiceAddress>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weights: Option<Weights>,
+    pub enable_tag_override: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<AgentServiceCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<Vec<AgentServiceCheck>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct AgentServiceCheck {
+    #[serde(rename = "CheckID", skip_serializing_if = "String::is_empty")]
+    pub check_id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    #[serde(rename = "HTTP", skip_serializing_if = "Option::is_none")]
+    pub http: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<HashMap<String, Vec<String>>>,
+    #[serde(rename = "TCP", skip_serializing_if = "Option::is_none")]
+    pub tcp: Option<String>,
+    #[serde(rename = "GRPC", skip_serializing_if = "Option::is_none")]
+    pub grpc: Option<String>,
+    #[serde(rename = "TTL", skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(rename = "TLSSkipVerify")]
+    pub tls_skip_verify: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deregister_critical_service_after: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct AgentCheckRegistration {
+    #[serde(rename = "ID", skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    #[serde(rename = "ServiceID", skip_serializing_if = "String::is_empty")]
+    pub service_id: String,
+    #[serde(flatten)]
+    pub check: AgentServiceCheck,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct CoordinateEntry {
+    pub node: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub segment: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partition: Option<String>,
+    pub coord: Coordinate,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct Coordinate {
+    pub vec: Vec<f64>,
+    pub error: f64,
+    pub adjustment: f64,
+    pub height: f64,
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+fn is_zero_port(value: &u16) -> bool {
+    *value == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn service_entry() -> Result<(), serde_json::Error> {
+        let test_json = r#"[{
+            "Node": {
+                "ID": "40e4a748-2192-161a-0510-9bf59fe950b5",
+                "Node": "foobar",
+                "Address": "10.1.10.12",
+                "Datacenter": "dc1",
+                "TaggedAddresses": {"lan": "10.1.10.12", "wan": "10.1.10.12"},
+                "Meta": {"instance_type": "t2.medium"},
+                "CreateIndex": 5,
+                "ModifyIndex": 6
+            },
+            "Service": {
+                "ID": "redis",
+                "Service": "redis",
+                "Tags": ["primary"],
+                "Address": "10.1.10.12",
+                "TaggedAddresses": {
+                    "lan": {"address": "10.1.10.12", "port": 8000}
+                },
+                "Meta": null,
+                "Port": 8000,
+                "Weights": {"Passing": 10, "Warning": 1},
+                "Namespace": "default"
+            },
+            "Checks": [{
+                "Node": "foobar",
+                "CheckID": "service:redis",
+                "Name": "Service 'redis' check",
+                "Status": "passing",
+                "Notes": "",
+                "Output": "",
+                "ServiceID": "redis",
+                "ServiceName": "redis",
+                "ServiceTags": ["primary"],
+                "Namespace": "default"
+            }]
+        }]"#;
+        let test_entries: Vec<ServiceEntry> = serde_json::from_str(test_json)?;
+        assert_eq!(test_entries.len(), 1);
+        assert_eq!(
+            test_entries[0].node.id,
+            "40e4a748-2192-161a-0510-9bf59fe950b5"
+        );
+        assert_eq!(test_entries[0].node.create_index, 5);
+        assert_eq!(test_entries[0].service.id, "redis");
+        assert_eq!(test_entries[0].service.port, 8000);
+        assert!(test_entries[0].service.meta.is_none());
+        assert_eq!(
+            test_entries[0].service.weights,
+            Some(Weights {
+                passing: 10,
+                warning: 1,
+            }),
+        );
+        assert_eq!(test_entries[0].checks[0].check_id, "service:redis");
+        assert_eq!(test_entries[0].checks[0].status, "passing");
+        Ok(())
+    }
+
+    #[test]
+    fn acl_token() -> Result<(), serde_json::Error> {
+        let test_json = r#"{
+            "AccessorID": "6a1253d2-1785-24fd-91c2-f8e78c745511",
+            "SecretID": "45a3bd52-07c7-47a4-52fd-0745e0cfe967",
+            "Description": "Agent token for 'node1'",
+            "Policies": [{"ID": "165d4317-e379-f732-ce70-86278c4558f7", "Name": "node1-write"}],
+            "Local": false,
+            "CreateTime": "2018-10-24T12:25:06.921933-04:00",
+            "Hash": "UuiRkOQPRCvoRZHRtUxxbrmwZ5crYrOdZ0Z1FTFbTbA=",
+            "CreateIndex": 59,
+            "ModifyIndex": 59
+        }"#;
+        let test_token: ACLToken = serde_json::from_str(test_json)?;
+        assert_eq!(
+            test_token.accessor_id,
+            "6a1253d2-1785-24fd-91c2-f8e78c745511"
+        );
+        assert_eq!(
+            test_token.secret_id.as_deref(),
+            Some("45a3bd52-07c7-47a4-52fd-0745e0cfe967"),
+        );
+        assert_eq!(test_token.policies.unwrap()[0].name, "node1-write");
+        assert!(test_token.roles.is_none());
+        assert_eq!(test_token.create_index, 59);
+        let test_serialized = serde_json::to_value(ACLLink {
+            id: String::from("test_id"),
+            name: String::from("test_name"),
+        })?;
+        assert_eq!(
+            test_serialized,
+            serde_json::json!({"ID": "test_id", "Name": "test_name"}),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn user_event() -> Result<(), serde_json::Error> {
+        let test_json = r#"[{
+            "ID": "b54fe110-7af5-cafc-d1fb-afc8ba432b1c",
+            "Name": "deploy",
+            "Payload": "MTYwOTAzMA==",
+            "NodeFilter": "",
+            "ServiceFilter": "",
+            "TagFilter": "",
+            "Version": 1,
+            "LTime": 19
+        }]"#;
+        let test_events: Vec<UserEvent> = serde_json::from_str(test_json)?;
+        assert_eq!(test_events[0].name, "deploy");
+        assert_eq!(test_events[0].payload.as_deref(), Some("MTYwOTAzMA=="));
+        assert_eq!(test_events[0].ltime, 19);
+        Ok(())
+    }
+
+    #[test]
+    fn agent_service_registration() -> Result<(), serde_json::Error> {
+        let test_registration = AgentServiceRegistration {
+            id: String::from("redis1"),
+            name: String::from("redis"),
+            tags: Some(vec![String::from("primary")]),
+            port: 8000,
+            check: Some(AgentServiceCheck {
+                http: Some(String::from("http://localhost:8000/health")),
+                interval: Some(String::from("10s")),
+                ..AgentServiceCheck::default()
+            }),
+            ..AgentServiceRegistration::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&test_registration)?,
+            serde_json::json!({
+                "ID": "redis1",
+                "Name": "redis",
+                "Tags": ["primary"],
+                "Port": 8000,
+                "EnableTagOverride": false,
+                "Check": {
+                    "HTTP": "http://localhost:8000/health",
+                    "Interval": "10s",
+                    "TLSSkipVerify": false
+                }
+            }),
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn agent_check_registration() -> Result<(), serde_json::Error> {
+        let test_registration = AgentCheckRegistration {
+            service_id: String::from("redis1"),
+            check: AgentServiceCheck {
+                name: String::from("mem"),
+                ttl: Some(String::from("15s")),
+                ..AgentServiceCheck::default()
+            },
+            ..AgentCheckRegistration::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&test_registration)?,
+            serde_json::json!({
+                "Name": "mem",
+                "ServiceID": "redis1",
+                "TTL": "15s",
+                "TLSSkipVerify": false
+            }),
+        );
+        let test_json = serde_json::to_string(&test_registration)?;
+        assert_eq!(test_json.matches("\"Name\"").count(), 1);
+        let test_decoded: AgentCheckRegistration = serde_json::from_str(&test_json)?;
+        assert_eq!(test_decoded, test_registration);
+        Ok(())
+    }
+
+    #[test]
+    fn session_entry() -> Result<(), serde_json::Error> {
+        let test_session = SessionEntry {
+            name: String::from("test_lock"),
+            behavior: String::from("delete"),
+            ttl: String::from("30s"),
+            ..SessionEntry::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&test_session)?,
+            serde_json::json!({
+                "Name": "test_lock",
+                "Behavior": "delete",
+                "TTL": "30s"
+            }),
+        );
+        Ok(())
+    }
+}
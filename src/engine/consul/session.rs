@@ -1,69 +1,146 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Idempotent, Method, QueryMeta, QueryOptions, Response, Runner};
+
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::SessionEntry;
 
 const SESSION_BASE_URL: &str = "/v1/session";
 
 impl Runner {
-    pub async fn session_create(&self) -> Result<Response<Body>, Error> {
+    pub async fn session_create(&self, payload: impl Into<Body>) -> Result<Response<Body>, Error> {
         let path = format!("{}/create", SESSION_BASE_URL);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(&path, None).await?;
+        let body = payload.into();
+        let request = self.build_request(method, uri, body, None).await?;
+        let endpoint = Endpoint::new("session_create", "/v1/session/create");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
     pub async fn session_destroy(&self, uuid: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/destroy/{}", SESSION_BASE_URL, uuid);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("session_destroy", "/v1/session/destroy/{uuid}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 
-    pub async fn session_info(&self, uuid: &str) -> Result<Response<Body>, Error> {
+    pub async fn session_info(
+        &self,
+        uuid: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/info/{}", SESSION_BASE_URL, uuid);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("session_info", "/v1/session/info/{uuid}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn session_node(&self, node: &str) -> Result<Response<Body>, Error> {
+    pub async fn session_node(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/node/{}", SESSION_BASE_URL, node);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("session_node", "/v1/session/node/{node}");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn session_list(&self) -> Result<Response<Body>, Error> {
+    pub async fn session_list(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/list", SESSION_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("session_list", "/v1/session/list");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
     pub async fn session_renew(&self, uuid: &str) -> Result<Response<Body>, Error> {
         let path = format!("{}/renew/{}", SESSION_BASE_URL, uuid);
         let method = Method::PUT;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, None).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Idempotent);
+        let endpoint = Endpoint::new("session_renew", "/v1/session/renew/{uuid}");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
 
+#[cfg(feature = "typed")]
+#[derive(serde::Deserialize)]
+struct SessionCreated {
+    #[serde(rename = "ID")]
+    id: String,
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn session_create_typed(&self, session: &SessionEntry) -> Result<String, Error> {
+        let payload = self.encode(session)?;
+        let response = self.session_create(payload).await?;
+        let created: SessionCreated = self.decode(response).await?;
+        Ok(created.id)
+    }
+
+    pub async fn session_info_typed(
+        &self,
+        uuid: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<SessionEntry>, QueryMeta), Error> {
+        let (response, meta) = self.session_info(uuid, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn session_node_typed(
+        &self,
+        node: &str,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<SessionEntry>, QueryMeta), Error> {
+        let (response, meta) = self.session_node(node, options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn session_list_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<SessionEntry>, QueryMeta), Error> {
+        let (response, meta) = self.session_list(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn session_renew_typed(&self, uuid: &str) -> Result<Vec<SessionEntry>, Error> {
+        let response = self.session_renew(uuid).await?;
+        let renewed = self.decode(response).await?;
+        Ok(renewed)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,16 +148,20 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn session_create() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/session/create")
+            .match_body(r#"{"Name": "test_session"}"#)
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.session_create().await?;
+            .create_async()
+            .await;
+        test_runner
+            .session_create(r#"{"Name": "test_session"}"#)
+            .await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -88,15 +169,16 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn session_destroy() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/session/destroy/test_uuid")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.session_destroy("test_uuid").await?;
         mock.assert();
         assert!(mock.matched());
@@ -105,16 +187,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn session_info() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/session/info/test_uuid")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.session_info("test_uuid").await?;
+            .create_async()
+            .await;
+        test_runner.session_info("test_uuid", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -122,16 +205,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn session_node() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/session/node/test_node")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.session_node("test_node").await?;
+            .create_async()
+            .await;
+        test_runner.session_node("test_node", None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -139,16 +223,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn session_list() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/session/list")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.session_list().await?;
+            .create_async()
+            .await;
+        test_runner.session_list(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -156,18 +241,47 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn session_renew() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/session/renew/test_uuid")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
+            .create_async()
+            .await;
         test_runner.session_renew("test_uuid").await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn session_create_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/session/create")
+            .match_body(mockito::Matcher::Json(serde_json::json!({
+                "Name": "test_session",
+                "TTL": "30s"
+            })))
+            .with_status(200)
+            .with_body(r#"{"ID": "adf4238a-882b-9ddc-4a9d-5b6758e4159e"}"#)
+            .create_async()
+            .await;
+        let test_session = SessionEntry {
+            name: String::from("test_session"),
+            ttl: String::from("30s"),
+            ..SessionEntry::default()
+        };
+        let test_id = test_runner.session_create_typed(&test_session).await?;
+        assert_eq!(test_id, "adf4238a-882b-9ddc-4a9d-5b6758e4159e");
+        mock.assert();
+        assert!(mock.matched());
+        Ok(())
+    }
 }
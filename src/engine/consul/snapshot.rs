@@ -1,25 +1,84 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner, Streaming};
+
+use hyper::body::HttpBody;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use std::path::Path;
 
 const SNAPSHOT_BASE_URL: &str = "/v1/snapshot";
 
 impl Runner {
-    pub async fn snapshot_generate(&self) -> Result<Response<Body>, Error> {
+    pub async fn snapshot_generate(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = SNAPSHOT_BASE_URL;
         let method = Method::GET;
-        let uri = self.build_uri(path).await;
+        let uri = self.build_uri(path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("snapshot_generate", "/v1/snapshot");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+
+    pub async fn save_snapshot_to<W>(
+        &self,
+        writer: &mut W,
+        options: Option<&QueryOptions>,
+    ) -> Result<(u64, QueryMeta), Error>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let (response, meta) = self.snapshot_generate(options).await?;
+        let mut body = response.into_body();
+        let mut written = 0;
+
+        while let Some(chunk) = body.data().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+
+        writer.flush().await?;
+
+        Ok((written, meta))
+    }
+
+    pub async fn save_snapshot_to_path(
+        &self,
+        path: impl AsRef<Path>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(u64, QueryMeta), Error> {
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::create(path).await?;
+
+        match self.save_snapshot_to(&mut file, options).await {
+            Ok(saved) => {
+                file.sync_all().await?;
+                Ok(saved)
+            }
+            Err(error) => {
+                drop(file);
+                let _ = tokio::fs::remove_file(path).await;
+                Err(error)
+            }
+        }
     }
 
-    pub async fn snapshot_restore(&self) -> Result<Response<Body>, Error> {
+    pub async fn snapshot_restore(
+        &self,
+        snapshot: impl Into<Body>,
+    ) -> Result<Response<Body>, Error> {
         let path = SNAPSHOT_BASE_URL;
         let method = Method::PUT;
-        let uri = self.build_uri(path).await;
-        let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
+        let uri = self.build_uri(path, None).await?;
+        let body = snapshot.into();
+        let mut request = self.build_request(method, uri, body, None).await?;
+        request.extensions_mut().insert(Streaming);
+        let endpoint = Endpoint::new("snapshot_restore", "/v1/snapshot");
+        let response = self.execute(&endpoint, request).await?;
         Ok(response)
     }
 }
@@ -31,35 +90,126 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn snapshot_generate() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/snapshot")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.snapshot_generate().await?;
+            .create_async()
+            .await;
+        test_runner.snapshot_generate(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn save_snapshot_to() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/snapshot")
+            .with_status(200)
+            .with_header("x-consul-index", "42")
+            .with_body("test_snapshot")
+            .create_async()
+            .await;
+        let mut test_writer = Vec::new();
+        let (test_written, test_meta) =
+            test_runner.save_snapshot_to(&mut test_writer, None).await?;
+        mock.assert();
+        assert_eq!(test_written, 13);
+        assert_eq!(test_meta.last_index, 42);
+        assert_eq!(test_writer, b"test_snapshot");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn save_snapshot_to_path() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let test_path = std::env::temp_dir().join("sublight_test_snapshot.snap");
+        let mock = test_server
+            .mock("GET", "/v1/snapshot")
+            .with_status(200)
+            .with_body("test_snapshot")
+            .create_async()
+            .await;
+        test_runner.save_snapshot_to_path(&test_path, None).await?;
+        mock.assert();
+        assert_eq!(std::fs::read(&test_path)?, b"test_snapshot");
+        std::fs::remove_file(&test_path)?;
+        let mock = test_server
+            .mock("GET", "/v1/snapshot")
+            .with_status(403)
+            .create_async()
+            .await;
+        assert!(test_runner
+            .save_snapshot_to_path(&test_path, None)
+            .await
+            .is_err());
+        mock.assert();
+        assert!(!test_path.exists());
+        Ok(())
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn snapshot_restore() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("PUT", "/v1/snapshot")
+            .match_body("test_snapshot")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.snapshot_restore().await?;
+            .create_async()
+            .await;
+        test_runner.snapshot_restore("test_snapshot").await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn snapshot_restore_streaming() -> Result<(), Error> {
+        let mut test_first = Server::new_async().await;
+        let mut test_second = Server::new_async().await;
+        let test_retry = crate::RetryPolicy::new()
+            .base_delay(std::time::Duration::from_millis(1))
+            .methods([Method::PUT]);
+        let test_runner = Runner::builder()
+            .addresses([test_first.url(), test_second.url()])
+            .retry_policy(test_retry)
+            .build()?;
+        let test_unavailable = test_first
+            .mock("PUT", "/v1/snapshot")
+            .match_body("test_snapshot")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let test_unused = test_second
+            .mock("PUT", "/v1/snapshot")
+            .expect(0)
+            .create_async()
+            .await;
+        let (mut test_sender, test_body) = Body::channel();
+        tokio::spawn(async move {
+            let _ = test_sender
+                .send_data(hyper::body::Bytes::from("test_snapshot"))
+                .await;
+        });
+        let test_result = test_runner.snapshot_restore(test_body).await;
+        assert!(matches!(test_result, Err(Error::Status { status, .. }) if status == 503));
+        test_unavailable.assert();
+        test_unused.assert();
+        Ok(())
+    }
 }
@@ -1,26 +1,57 @@
-use crate::{Body, Error, Method, Response, Runner};
+use crate::{Body, Endpoint, Error, Method, QueryMeta, QueryOptions, Response, Runner};
 
 const STATUS_BASE_URL: &str = "/v1/status";
 
 impl Runner {
-    pub async fn status_leader(&self) -> Result<Response<Body>, Error> {
+    pub async fn status_leader(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/leader", STATUS_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("status_leader", "/v1/status/leader");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    pub async fn status_peers(&self) -> Result<Response<Body>, Error> {
+    pub async fn status_peers(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
         let path = format!("{}/peers", STATUS_BASE_URL);
         let method = Method::GET;
-        let uri = self.build_uri(&path).await;
+        let uri = self.build_uri(&path, options).await?;
         let body = Body::empty();
-        let request = self.build_request(method, uri, body).await;
-        let response = self.client.request(request).await?;
-        Ok(response)
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("status_peers", "/v1/status/peers");
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
+    }
+}
+
+#[cfg(feature = "typed")]
+impl Runner {
+    pub async fn status_leader_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(String, QueryMeta), Error> {
+        let (response, meta) = self.status_leader(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
+    }
+
+    pub async fn status_peers_typed(
+        &self,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Vec<String>, QueryMeta), Error> {
+        let (response, meta) = self.status_peers(options).await?;
+        let decoded = self.decode(response).await?;
+        Ok((decoded, meta))
     }
 }
 
@@ -31,16 +62,17 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn status_leader() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/status/leader")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.status_leader().await?;
+            .create_async()
+            .await;
+        test_runner.status_leader(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
@@ -48,18 +80,38 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread")]
     async fn status_peers() -> Result<(), Error> {
-        let mut test_server = Server::new();
+        let mut test_server = Server::new_async().await;
         let test_mock_url = test_server.url();
-        let test_runner = Runner::init(&test_mock_url, None, None).await;
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
         let mock = test_server
             .mock("GET", "/v1/status/peers")
             .with_status(200)
             .with_header("user-agent", "sublight/0.1.0")
             .with_body("")
-            .create();
-        test_runner.status_peers().await?;
+            .create_async()
+            .await;
+        test_runner.status_peers(None).await?;
         mock.assert();
         assert!(mock.matched());
         Ok(())
     }
+
+    #[cfg(feature = "typed")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn status_peers_typed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/status/peers")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"["10.1.10.12:8300", "10.1.10.11:8300"]"#)
+            .create_async()
+            .await;
+        let (test_peers, _) = test_runner.status_peers_typed(None).await?;
+        assert_eq!(test_peers, vec!["10.1.10.12:8300", "10.1.10.11:8300"]);
+        mock.assert();
+        Ok(())
+    }
 }
@@ -0,0 +1,478 @@
+use crate::engine::consul::kv_store::{base64_value, KVPair};
+#[cfg(feature = "typed")]
+use crate::engine::consul::models::{AgentService, HealthCheck, Node};
+use crate::{Bytes, Endpoint, Error, Method, QueryOptions, Runner};
+
+use hyper::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize};
+
+const TXN_PATH: &str = "/v1/txn";
+
+pub const MAX_TXN_OPERATIONS: usize = 64;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KVVerb {
+    Set,
+    Cas,
+    Lock,
+    Unlock,
+    Get,
+    GetTree,
+    CheckIndex,
+    CheckSession,
+    CheckNotExists,
+    Delete,
+    DeleteTree,
+    DeleteCas,
+}
+
+#[cfg(feature = "typed")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CatalogVerb {
+    Set,
+    Cas,
+    Get,
+    Delete,
+    DeleteCas,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct KVOperation {
+    pub verb: KVVerb,
+    pub key: String,
+    #[serde(
+        serialize_with = "base64_value::serialize",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub value: Option<Bytes>,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub flags: u64,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub index: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
+}
+
+impl KVOperation {
+    pub fn new(verb: KVVerb, key: impl Into<String>) -> KVOperation {
+        KVOperation {
+            verb,
+            key: key.into(),
+            value: None,
+            flags: 0,
+            index: 0,
+            session: None,
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<Bytes>) -> KVOperation {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn flags(mut self, flags: u64) -> KVOperation {
+        self.flags = flags;
+        self
+    }
+
+    pub fn index(mut self, index: u64) -> KVOperation {
+        self.index = index;
+        self
+    }
+
+    pub fn session(mut self, session: impl Into<String>) -> KVOperation {
+        self.session = Some(session.into());
+        self
+    }
+}
+
+#[cfg(feature = "typed")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NodeOperation {
+    pub verb: CatalogVerb,
+    pub node: Node,
+}
+
+#[cfg(feature = "typed")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ServiceOperation {
+    pub verb: CatalogVerb,
+    pub node: String,
+    pub service: AgentService,
+}
+
+#[cfg(feature = "typed")]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CheckOperation {
+    pub verb: CatalogVerb,
+    pub check: HealthCheck,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum TxnOperation {
+    #[serde(rename = "KV")]
+    KV(KVOperation),
+    #[cfg(feature = "typed")]
+    Node(NodeOperation),
+    #[cfg(feature = "typed")]
+    Service(ServiceOperation),
+    #[cfg(feature = "typed")]
+    Check(CheckOperation),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Txn {
+    operations: Vec<TxnOperation>,
+}
+
+impl Txn {
+    pub fn new() -> Txn {
+        Txn::default()
+    }
+
+    pub fn operation(mut self, operation: TxnOperation) -> Txn {
+        self.operations.push(operation);
+        self
+    }
+
+    pub fn kv(self, operation: KVOperation) -> Txn {
+        self.operation(TxnOperation::KV(operation))
+    }
+
+    pub fn kv_set(self, key: impl Into<String>, value: impl Into<Bytes>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::Set, key).value(value))
+    }
+
+    pub fn kv_cas(self, key: impl Into<String>, value: impl Into<Bytes>, index: u64) -> Txn {
+        self.kv(KVOperation::new(KVVerb::Cas, key).value(value).index(index))
+    }
+
+    pub fn kv_lock(
+        self,
+        key: impl Into<String>,
+        value: impl Into<Bytes>,
+        session: impl Into<String>,
+    ) -> Txn {
+        let operation = KVOperation::new(KVVerb::Lock, key)
+            .value(value)
+            .session(session);
+        self.kv(operation)
+    }
+
+    pub fn kv_unlock(
+        self,
+        key: impl Into<String>,
+        value: impl Into<Bytes>,
+        session: impl Into<String>,
+    ) -> Txn {
+        let operation = KVOperation::new(KVVerb::Unlock, key)
+            .value(value)
+            .session(session);
+        self.kv(operation)
+    }
+
+    pub fn kv_get(self, key: impl Into<String>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::Get, key))
+    }
+
+    pub fn kv_get_tree(self, prefix: impl Into<String>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::GetTree, prefix))
+    }
+
+    pub fn kv_check_index(self, key: impl Into<String>, index: u64) -> Txn {
+        self.kv(KVOperation::new(KVVerb::CheckIndex, key).index(index))
+    }
+
+    pub fn kv_check_session(self, key: impl Into<String>, session: impl Into<String>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::CheckSession, key).session(session))
+    }
+
+    pub fn kv_check_not_exists(self, key: impl Into<String>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::CheckNotExists, key))
+    }
+
+    pub fn kv_delete(self, key: impl Into<String>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::Delete, key))
+    }
+
+    pub fn kv_delete_tree(self, prefix: impl Into<String>) -> Txn {
+        self.kv(KVOperation::new(KVVerb::DeleteTree, prefix))
+    }
+
+    pub fn kv_delete_cas(self, key: impl Into<String>, index: u64) -> Txn {
+        self.kv(KVOperation::new(KVVerb::DeleteCas, key).index(index))
+    }
+
+    #[cfg(feature = "typed")]
+    pub fn node(self, verb: CatalogVerb, node: Node) -> Txn {
+        self.operation(TxnOperation::Node(NodeOperation { verb, node }))
+    }
+
+    #[cfg(feature = "typed")]
+    pub fn service(self, verb: CatalogVerb, node: impl Into<String>, service: AgentService) -> Txn {
+        let node = node.into();
+        self.operation(TxnOperation::Service(ServiceOperation {
+            verb,
+            node,
+            service,
+        }))
+    }
+
+    #[cfg(feature = "typed")]
+    pub fn check(self, verb: CatalogVerb, check: HealthCheck) -> Txn {
+        self.operation(TxnOperation::Check(CheckOperation { verb, check }))
+    }
+
+    pub fn operations(&self) -> &[TxnOperation] {
+        &self.operations
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    fn validate(&self) -> Result<(), Error> {
+        match self.operations.len() {
+            0 => Err(Error::InvalidConfig(String::from(
+                "transaction has no operations",
+            ))),
+            length if length > MAX_TXN_OPERATIONS => Err(Error::InvalidConfig(format!(
+                "transaction has {} operations, the limit is {}",
+                length, MAX_TXN_OPERATIONS,
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub enum TxnResult {
+    #[serde(rename = "KV")]
+    KV(KVPair),
+    #[cfg(feature = "typed")]
+    Node(Node),
+    #[cfg(feature = "typed")]
+    Service(AgentService),
+    #[cfg(feature = "typed")]
+    Check(HealthCheck),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TxnError {
+    pub op_index: usize,
+    pub what: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct TxnResponse {
+    #[serde(deserialize_with = "nullable")]
+    pub results: Vec<TxnResult>,
+    #[serde(deserialize_with = "nullable")]
+    pub errors: Vec<TxnError>,
+}
+
+impl TxnResponse {
+    pub fn is_committed(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl Runner {
+    pub async fn txn(
+        &self,
+        txn: &Txn,
+        options: Option<&QueryOptions>,
+    ) -> Result<TxnResponse, Error> {
+        txn.validate()?;
+
+        let method = Method::PUT;
+        let uri = self.build_uri(TXN_PATH, options).await?;
+        let body = self.encode(&txn.operations)?;
+        let request = self.build_request(method, uri, body, options).await?;
+        let endpoint = Endpoint::new("txn", "/v1/txn");
+
+        match self.execute(&endpoint, request).await {
+            Ok(response) => self.decode(response).await,
+            Err(Error::Status {
+                status: StatusCode::CONFLICT,
+                body,
+            }) => Ok(serde_json::from_str(&body)?),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+fn is_zero(value: &u64) -> bool {
+    *value == 0
+}
+
+fn nullable<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn txn() -> Result<(), Error> {
+        let test_txn = Txn::new()
+            .kv_set("test_flag", "true")
+            .kv_cas("test_version", "2", 42)
+            .kv_lock("test_lock", "", "test_session")
+            .kv_check_not_exists("test_missing")
+            .kv_delete_tree("test_prefix/")
+            .kv(KVOperation::new(KVVerb::Set, "test_flags").flags(7));
+        assert_eq!(test_txn.len(), 6);
+        let test_json = serde_json::to_value(test_txn.operations())?;
+        assert_eq!(
+            test_json[0],
+            json!({"KV": {"Verb": "set", "Key": "test_flag", "Value": "dHJ1ZQ=="}}),
+        );
+        assert_eq!(
+            test_json[1],
+            json!({"KV": {"Verb": "cas", "Key": "test_version", "Value": "Mg==", "Index": 42}}),
+        );
+        assert_eq!(
+            test_json[2],
+            json!({"KV": {"Verb": "lock", "Key": "test_lock", "Value": "", "Session": "test_session"}}),
+        );
+        assert_eq!(
+            test_json[3],
+            json!({"KV": {"Verb": "check-not-exists", "Key": "test_missing"}}),
+        );
+        assert_eq!(
+            test_json[4],
+            json!({"KV": {"Verb": "delete-tree", "Key": "test_prefix/"}}),
+        );
+        assert_eq!(
+            test_json[5],
+            json!({"KV": {"Verb": "set", "Key": "test_flags", "Flags": 7}}),
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "typed")]
+    #[test]
+    fn txn_catalog() -> Result<(), Error> {
+        let test_txn = Txn::new().node(
+            CatalogVerb::Get,
+            Node {
+                node: String::from("test_node"),
+                ..Node::default()
+            },
+        );
+        let test_json = serde_json::to_value(test_txn.operations())?;
+        assert_eq!(test_json[0]["Node"]["Verb"], "get");
+        assert_eq!(test_json[0]["Node"]["Node"]["Node"], "test_node");
+        let test_response: TxnResponse = serde_json::from_str(
+            r#"{"Results": [{"Node": {"Node": "test_node"}}], "Errors": null}"#,
+        )?;
+        assert!(
+            matches!(&test_response.results[0], TxnResult::Node(test_node) if test_node.node == "test_node")
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn txn_limit() -> Result<(), Error> {
+        let test_runner = Runner::init("http://127.0.0.1:8500", None, None).await?;
+        let test_error = test_runner.txn(&Txn::new(), None).await.unwrap_err();
+        assert!(matches!(test_error, Error::InvalidConfig(_)));
+        let test_txn = (0..=MAX_TXN_OPERATIONS).fold(Txn::new(), |test_txn, test_index| {
+            test_txn.kv_get(format!("test_key_{}", test_index))
+        });
+        let test_error = test_runner.txn(&test_txn, None).await.unwrap_err();
+        assert!(matches!(test_error, Error::InvalidConfig(_)));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn txn_committed() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/txn")
+            .match_query("dc=test_dc")
+            .match_body(Matcher::Json(json!([
+                {"KV": {"Verb": "set", "Key": "test_flag", "Value": "dHJ1ZQ=="}},
+                {"KV": {"Verb": "set", "Key": "test_version", "Value": "Mg=="}},
+                {"KV": {"Verb": "get", "Key": "test_flag"}},
+            ])))
+            .with_status(200)
+            .with_body(
+                r#"{"Results": [
+                    {"KV": {"Key": "test_flag", "Value": null, "ModifyIndex": 10}},
+                    {"KV": {"Key": "test_version", "Value": null, "ModifyIndex": 10}},
+                    {"KV": {"Key": "test_flag", "Value": "dHJ1ZQ==", "ModifyIndex": 10}}
+                ], "Errors": null}"#,
+            )
+            .create_async()
+            .await;
+        let test_txn = Txn::new()
+            .kv_set("test_flag", "true")
+            .kv_set("test_version", "2")
+            .kv_get("test_flag");
+        let test_options = QueryOptions::new().datacenter("test_dc");
+        let test_response = test_runner.txn(&test_txn, Some(&test_options)).await?;
+        mock.assert();
+        assert!(test_response.is_committed());
+        assert_eq!(test_response.results.len(), 3);
+        assert!(matches!(
+            &test_response.results[2],
+            TxnResult::KV(test_pair) if test_pair.value_str()? == Some("true")
+        ));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn txn_rolled_back() -> Result<(), Error> {
+        let mut test_server = Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("PUT", "/v1/txn")
+            .with_status(409)
+            .with_body(
+                r#"{"Results": null, "Errors": [{"OpIndex": 1, "What": "current modify index 43 does not match"}]}"#,
+            )
+            .create_async()
+            .await;
+        let test_txn = Txn::new()
+            .kv_set("test_flag", "true")
+            .kv_cas("test_version", "2", 42);
+        let test_response = test_runner.txn(&test_txn, None).await?;
+        mock.assert();
+        assert!(!test_response.is_committed());
+        assert!(test_response.results.is_empty());
+        assert_eq!(test_response.errors[0].op_index, 1);
+        let mock = test_server
+            .mock("PUT", "/v1/txn")
+            .with_status(500)
+            .create_async()
+            .await;
+        let test_error = test_runner.txn(&test_txn, None).await.unwrap_err();
+        mock.assert();
+        assert_eq!(test_error.status(), Some(StatusCode::INTERNAL_SERVER_ERROR));
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{proxy, trace};
+use crate::{Error, RunnerBuilder};
+
+use hyper::Uri;
+
+use std::str::FromStr;
+
+const HTTP_ADDR: &str = "CONSUL_HTTP_ADDR";
+const HTTP_TOKEN: &str = "CONSUL_HTTP_TOKEN";
+const HTTP_TOKEN_FILE: &str = "CONSUL_HTTP_TOKEN_FILE";
+const HTTP_SSL: &str = "CONSUL_HTTP_SSL";
+const HTTP_SSL_VERIFY: &str = "CONSUL_HTTP_SSL_VERIFY";
+const CACERT: &str = "CONSUL_CACERT";
+const CAPATH: &str = "CONSUL_CAPATH";
+const CLIENT_CERT: &str = "CONSUL_CLIENT_CERT";
+const CLIENT_KEY: &str = "CONSUL_CLIENT_KEY";
+const TLS_SERVER_NAME: &str = "CONSUL_TLS_SERVER_NAME";
+const NAMESPACE: &str = "CONSUL_NAMESPACE";
+const PARTITION: &str = "CONSUL_PARTITION";
+const HTTPS_PROXY: [&str; 2] = ["HTTPS_PROXY", "https_proxy"];
+const NO_PROXY: [&str; 2] = ["NO_PROXY", "no_proxy"];
+
+const DEFAULT_HTTP_ADDR: &str = "127.0.0.1:8500";
+
+impl RunnerBuilder {
+    pub fn from_env() -> Result<RunnerBuilder, Error> {
+        RunnerBuilder::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<RunnerBuilder, Error> {
+        let lookup = |name: &str| lookup(name).filter(|value| !value.is_empty());
+        let mut builder = RunnerBuilder::new();
+
+        let ssl = match lookup(HTTP_SSL) {
+            Some(value) => parse_bool(HTTP_SSL, &value)?,
+            None => false,
+        };
+
+        let address = lookup(HTTP_ADDR).unwrap_or_else(|| String::from(DEFAULT_HTTP_ADDR));
+
+        let address = match address.split_once("://") {
+            Some(("http", host)) if ssl => format!("https://{}", host),
+            Some(_) => address,
+            None if ssl => format!("https://{}", address),
+            None => format!("http://{}", address),
+        };
+
+        let https = address.starts_with("https://");
+        let destination = Uri::from_str(&address).ok();
+        builder = builder.address(address);
+
+        let token_from_file = match lookup(HTTP_TOKEN_FILE) {
+            Some(token_file) => {
+                let token = std::fs::read_to_string(&token_file).map_err(|error| {
+                    let reason = format!(
+                        "could not read {} {}: {}",
+                        HTTP_TOKEN_FILE, token_file, error
+                    );
+                    Error::InvalidConfig(reason)
+                })?;
+
+                Some(token.trim().to_owned()).filter(|token| !token.is_empty())
+            }
+            None => None,
+        };
+
+        if let Some(token) = token_from_file.or_else(|| lookup(HTTP_TOKEN)) {
+            builder = builder.token(token);
+        }
+
+        if let Some(ca_file) = lookup(CACERT) {
+            builder = builder.ca_file(ca_file);
+        }
+
+        if let Some(ca_path) = lookup(CAPATH) {
+            builder = builder.ca_path(ca_path);
+        }
+
+        match (lookup(CLIENT_CERT), lookup(CLIENT_KEY)) {
+            (Some(client_cert), Some(client_key)) => {
+                builder = builder.client_cert(client_cert, client_key);
+            }
+            (None, None) => {}
+            _ => {
+                let reason = format!("{} and {} must be set together", CLIENT_CERT, CLIENT_KEY);
+                return Err(Error::InvalidConfig(reason));
+            }
+        }
+
+        if let Some(server_name) = lookup(TLS_SERVER_NAME) {
+            builder = builder.tls_server_name(server_name);
+        }
+
+        if let Some(value) = lookup(HTTP_SSL_VERIFY) {
+            builder = builder.tls_insecure_skip_verify(!parse_bool(HTTP_SSL_VERIFY, &value)?);
+        }
+
+        if let Some(namespace) = lookup(NAMESPACE) {
+            builder = builder.namespace(namespace);
+        }
+
+        if let Some(partition) = lookup(PARTITION) {
+            builder = builder.partition(partition);
+        }
+
+        let no_proxy = NO_PROXY.into_iter().find_map(lookup);
+        let bypassed = match &destination {
+            Some(destination) => {
+                proxy::bypasses(&proxy::no_proxy_hosts(no_proxy.as_deref()), destination)
+            }
+            None => false,
+        };
+
+        if let Some(proxy) = HTTPS_PROXY.into_iter().find_map(lookup) {
+            if https && !bypassed {
+                match proxy::supported(&proxy) {
+                    true => builder = builder.proxy(proxy),
+                    false => trace::unsupported_proxy(proxy::scheme(&proxy)),
+                }
+            }
+        }
+
+        if let Some(no_proxy) = no_proxy {
+            builder = builder.no_proxy(no_proxy);
+        }
+
+        Ok(builder)
+    }
+}
+
+fn parse_bool(name: &str, value: &str) -> Result<bool, Error> {
+    match value {
+        "1" | "t" | "T" | "true" | "TRUE" | "True" => Ok(true),
+        "0" | "f" | "F" | "false" | "FALSE" | "False" => Ok(false),
+        _ => {
+            let reason = format!("{} has an invalid boolean value {}", name, value);
+            Err(Error::InvalidConfig(reason))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn test_lookup(variables: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let variables: HashMap<String, String> = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+
+        move |name| variables.get(name).cloned()
+    }
+
+    #[test]
+    fn from_lookup_defaults() -> Result<(), Error> {
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[]))?.build()?;
+        assert_eq!(test_runner.endpoint, "http://127.0.0.1:8500/");
+        assert!(test_runner.default_headers.get("x-consul-token").is_none());
+        assert!(test_runner.namespace.is_none());
+        assert!(test_runner.partition.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn from_lookup() -> Result<(), Error> {
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "consul.example.com:8501"),
+            ("CONSUL_HTTP_SSL", "true"),
+            ("CONSUL_HTTP_TOKEN", "test_token"),
+            ("CONSUL_TLS_SERVER_NAME", "server.dc1.consul"),
+            ("CONSUL_HTTP_SSL_VERIFY", "false"),
+            ("CONSUL_NAMESPACE", "test_ns"),
+            ("CONSUL_PARTITION", "test_partition"),
+        ]))?
+        .build()?;
+        assert_eq!(test_runner.endpoint, "https://consul.example.com:8501/");
+        assert_eq!(
+            test_runner.default_headers.get("x-consul-token").unwrap(),
+            "test_token",
+        );
+        assert_eq!(test_runner.namespace.as_deref(), Some("test_ns"));
+        assert_eq!(test_runner.partition.as_deref(), Some("test_partition"));
+        Ok(())
+    }
+
+    #[test]
+    fn from_lookup_address_scheme() -> Result<(), Error> {
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "http://consul.example.com:8500"),
+            ("CONSUL_HTTP_SSL", "true"),
+        ]))?
+        .build()?;
+        assert_eq!(test_runner.endpoint, "https://consul.example.com:8500/");
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "https://consul.example.com:8501"),
+            ("CONSUL_HTTP_SSL", "false"),
+        ]))?
+        .build()?;
+        assert_eq!(test_runner.endpoint, "https://consul.example.com:8501/");
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[(
+            "CONSUL_HTTP_ADDR",
+            "unix:///var/run/consul/consul_http.sock",
+        )]))?
+        .build()?;
+        assert_eq!(test_runner.endpoint, "http://localhost/");
+        Ok(())
+    }
+
+    #[test]
+    fn from_lookup_proxy() -> Result<(), Error> {
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "consul.example.com:8500"),
+            ("HTTPS_PROXY", "https://proxy.example.com"),
+        ]))?
+        .build();
+        assert!(test_runner.is_ok());
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "https://consul.example.com:8501"),
+            ("https_proxy", "https://proxy.example.com"),
+            ("NO_PROXY", "example.com"),
+        ]))?
+        .build();
+        assert!(test_runner.is_ok());
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "https://consul.example.com:8501"),
+            ("https_proxy", "https://proxy.example.com"),
+            ("NO_PROXY", "other.example.com"),
+        ]))?
+        .build();
+        assert!(test_runner.is_ok());
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_ADDR", "https://consul.example.com:8501"),
+            ("https_proxy", "socks5://proxy.example.com"),
+        ]))?
+        .build();
+        assert!(test_runner.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn from_lookup_token_file() -> Result<(), Error> {
+        let test_token_file = std::env::temp_dir().join("sublight_test_token_file");
+        std::fs::write(&test_token_file, "test_file_token\n").unwrap();
+        let test_runner = RunnerBuilder::from_lookup(test_lookup(&[
+            ("CONSUL_HTTP_TOKEN", "test_token"),
+            ("CONSUL_HTTP_TOKEN_FILE", test_token_file.to_str().unwrap()),
+        ]))?
+        .build()?;
+        std::fs::remove_file(&test_token_file).unwrap();
+        assert_eq!(
+            test_runner.default_headers.get("x-consul-token").unwrap(),
+            "test_file_token",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_lookup_invalid() {
+        let test_ssl = RunnerBuilder::from_lookup(test_lookup(&[("CONSUL_HTTP_SSL", "yes")]));
+        assert!(matches!(test_ssl, Err(Error::InvalidConfig(_))));
+        let test_ssl_verify =
+            RunnerBuilder::from_lookup(test_lookup(&[("CONSUL_HTTP_SSL_VERIFY", "no")]));
+        assert!(matches!(test_ssl_verify, Err(Error::InvalidConfig(_))));
+        let test_ca_path =
+            RunnerBuilder::from_lookup(test_lookup(&[("CONSUL_CAPATH", "/test/missing/ca")]))
+                .and_then(RunnerBuilder::build);
+        assert!(matches!(test_ca_path, Err(Error::InvalidConfig(_))));
+        let test_token_file = RunnerBuilder::from_lookup(test_lookup(&[(
+            "CONSUL_HTTP_TOKEN_FILE",
+            "/test/missing/token",
+        )]));
+        assert!(matches!(test_token_file, Err(Error::InvalidConfig(_))));
+        let test_client_cert =
+            RunnerBuilder::from_lookup(test_lookup(&[("CONSUL_CLIENT_CERT", "/test/client.pem")]));
+        assert!(matches!(test_client_cert, Err(Error::InvalidConfig(_))));
+    }
+}
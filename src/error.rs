@@ -0,0 +1,232 @@
+use hyper::StatusCode;
+
+use std::fmt;
+use std::io;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidConfig(String),
+    Transport(BoxError),
+    Timeout,
+    Status {
+        status: StatusCode,
+        body: String,
+    },
+    Decode(BoxError),
+    Io(io::Error),
+    RolledBack {
+        applied: Vec<String>,
+        errors: Vec<String>,
+        source: Option<Box<Error>>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    InvalidConfig,
+    Transport,
+    Timeout,
+    Status,
+    Decode,
+    Io,
+    RolledBack,
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::InvalidConfig => "invalid_config",
+            ErrorKind::Transport => "transport",
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::Status => "status",
+            ErrorKind::Decode => "decode",
+            ErrorKind::Io => "io",
+            ErrorKind::RolledBack => "rolled_back",
+        }
+    }
+}
+
+impl Error {
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Error::Timeout)
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidConfig(_) => ErrorKind::InvalidConfig,
+            Error::Transport(_) => ErrorKind::Transport,
+            Error::Timeout => ErrorKind::Timeout,
+            Error::Status { .. } => ErrorKind::Status,
+            Error::Decode(_) => ErrorKind::Decode,
+            Error::Io(_) => ErrorKind::Io,
+            Error::RolledBack { .. } => ErrorKind::RolledBack,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidConfig(reason) => write!(f, "invalid configuration: {}", reason),
+            Error::Transport(error) => write!(f, "transport error: {}", error),
+            Error::Timeout => write!(f, "request timed out"),
+            Error::Status { status, body } if body.is_empty() => {
+                write!(f, "unexpected status {}", status)
+            }
+            Error::Status { status, body } => {
+                write!(f, "unexpected status {}: {}", status, body.trim_end())
+            }
+            Error::Decode(error) => write!(f, "decode error: {}", error),
+            Error::Io(error) => write!(f, "io error: {}", error),
+            Error::RolledBack {
+                applied,
+                errors,
+                source,
+            } => {
+                write!(
+                    f,
+                    "transaction rolled back after {} keys were applied: ",
+                    applied.len(),
+                )?;
+
+                match source {
+                    Some(source) => write!(f, "{}", source),
+                    None => write!(f, "{}", errors.join("; ")),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(error) | Error::Decode(error) => Some(error.as_ref()),
+            Error::Io(error) => Some(error),
+            Error::RolledBack {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
+
+impl From<hyper::Error> for Error {
+    fn from(error: hyper::Error) -> Error {
+        Error::Transport(Box::new(error))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Error {
+        Error::Decode(Box::new(error))
+    }
+}
+
+impl From<http::Error> for Error {
+    fn from(error: http::Error) -> Error {
+        Error::InvalidConfig(error.to_string())
+    }
+}
+
+impl From<http::uri::InvalidUri> for Error {
+    fn from(error: http::uri::InvalidUri) -> Error {
+        Error::InvalidConfig(error.to_string())
+    }
+}
+
+impl From<http::header::InvalidHeaderName> for Error {
+    fn from(error: http::header::InvalidHeaderName) -> Error {
+        Error::InvalidConfig(error.to_string())
+    }
+}
+
+impl From<http::header::InvalidHeaderValue> for Error {
+    fn from(error: http::header::InvalidHeaderValue) -> Error {
+        Error::InvalidConfig(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let test_invalid_config = Error::InvalidConfig(String::from("missing scheme"));
+        assert_eq!(
+            test_invalid_config.to_string(),
+            "invalid configuration: missing scheme",
+        );
+        assert_eq!(Error::Timeout.to_string(), "request timed out");
+        let test_status = Error::Status {
+            status: StatusCode::FORBIDDEN,
+            body: String::from("ACL not found\n"),
+        };
+        assert_eq!(
+            test_status.to_string(),
+            "unexpected status 403 Forbidden: ACL not found",
+        );
+        let test_empty_status = Error::Status {
+            status: StatusCode::NOT_FOUND,
+            body: String::new(),
+        };
+        assert_eq!(
+            test_empty_status.to_string(),
+            "unexpected status 404 Not Found",
+        );
+        let test_rolled_back = Error::RolledBack {
+            applied: vec![String::from("test_key")],
+            errors: vec![String::from("test_conflict"), String::from("test_missing")],
+            source: None,
+        };
+        assert_eq!(
+            test_rolled_back.to_string(),
+            "transaction rolled back after 1 keys were applied: test_conflict; test_missing",
+        );
+        assert_eq!(test_rolled_back.kind().as_str(), "rolled_back");
+        let test_rolled_back = Error::RolledBack {
+            applied: Vec::new(),
+            errors: Vec::new(),
+            source: Some(Box::new(Error::Timeout)),
+        };
+        assert_eq!(
+            test_rolled_back.to_string(),
+            "transaction rolled back after 0 keys were applied: request timed out",
+        );
+        assert!(std::error::Error::source(&test_rolled_back).is_some());
+    }
+
+    #[test]
+    fn status() {
+        let test_status = Error::Status {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            body: String::from("No cluster leader"),
+        };
+        assert_eq!(
+            test_status.status(),
+            Some(StatusCode::INTERNAL_SERVER_ERROR)
+        );
+        assert!(!test_status.is_timeout());
+        assert_eq!(Error::Timeout.status(), None);
+        assert!(Error::Timeout.is_timeout());
+        assert_eq!(test_status.kind(), ErrorKind::Status);
+        assert_eq!(Error::Timeout.kind().as_str(), "timeout");
+    }
+}
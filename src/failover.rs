@@ -0,0 +1,428 @@
+use crate::{Error, Runner};
+
+use http::uri::{Authority, Scheme};
+use hyper::{Body, Method, StatusCode, Uri};
+
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+const STATUS_PEERS_PATH: &str = "/v1/status/peers";
+const NO_CLUSTER_LEADER: &str = "No cluster leader";
+
+pub struct FailoverEvent<'a> {
+    pub from: &'a Authority,
+    pub to: &'a Authority,
+    pub error: &'a Error,
+}
+
+pub(crate) type FailoverCallback = Arc<dyn Fn(&FailoverEvent<'_>) + Send + Sync>;
+
+struct Server {
+    scheme: Scheme,
+    authority: Authority,
+    healthy: AtomicBool,
+}
+
+pub(crate) struct Servers {
+    configured: Vec<(Scheme, Authority)>,
+    servers: RwLock<Arc<Vec<Server>>>,
+    current: AtomicUsize,
+    refresh_interval: Option<Duration>,
+    refreshed: Mutex<Instant>,
+    on_failover: Option<FailoverCallback>,
+}
+
+impl Servers {
+    pub(crate) fn new(
+        servers: Vec<(Scheme, Authority)>,
+        refresh_interval: Option<Duration>,
+        on_failover: Option<FailoverCallback>,
+    ) -> Servers {
+        Servers {
+            servers: RwLock::new(Arc::new(collect(servers.clone()))),
+            configured: servers,
+            current: AtomicUsize::new(0),
+            refresh_interval,
+            refreshed: Mutex::new(Instant::now()),
+            on_failover,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.servers.read().unwrap().len()
+    }
+
+    pub(crate) fn current(&self) -> (usize, Scheme, Authority) {
+        let servers = self.servers.read().unwrap().clone();
+        let index = self.current.load(Ordering::Acquire) % servers.len();
+        let server = &servers[index];
+
+        (index, server.scheme.clone(), server.authority.clone())
+    }
+
+    pub(crate) fn succeeded(&self, index: usize) {
+        let servers = self.servers.read().unwrap().clone();
+
+        if let Some(server) = servers.get(index) {
+            server.healthy.store(true, Ordering::Release);
+        }
+    }
+
+    pub(crate) fn failed(&self, index: usize, error: &Error) {
+        let servers = self.servers.read().unwrap().clone();
+
+        let from = match servers.get(index) {
+            Some(server) => server,
+            None => return,
+        };
+
+        from.healthy.store(false, Ordering::Release);
+
+        if servers.len() == 1 {
+            return;
+        }
+
+        let next = (1..servers.len())
+            .map(|offset| (index + offset) % servers.len())
+            .find(|next| servers[*next].healthy.load(Ordering::Acquire));
+
+        let next = match next {
+            Some(next) => next,
+            None => {
+                for server in servers.iter() {
+                    server.healthy.store(true, Ordering::Release);
+                }
+
+                (index + 1) % servers.len()
+            }
+        };
+
+        let swapped =
+            self.current
+                .compare_exchange(index, next, Ordering::AcqRel, Ordering::Acquire);
+
+        if let (Ok(_), Some(on_failover)) = (swapped, &self.on_failover) {
+            on_failover(&FailoverEvent {
+                from: &from.authority,
+                to: &servers[next].authority,
+                error,
+            });
+        }
+    }
+
+    pub(crate) fn add_peers(&self, peers: Vec<(Scheme, Authority)>) {
+        let mut servers = self.configured.clone();
+
+        for peer in peers {
+            if !servers.iter().any(|(_, authority)| *authority == peer.1) {
+                servers.push(peer);
+            }
+        }
+
+        let (_, _, current) = self.current();
+        let index = servers
+            .iter()
+            .position(|(_, authority)| *authority == current)
+            .unwrap_or_default();
+
+        *self.servers.write().unwrap() = Arc::new(collect(servers));
+        self.current.store(index, Ordering::Release);
+    }
+
+    pub(crate) fn refresh_due(&self) -> bool {
+        let refresh_interval = match self.refresh_interval {
+            Some(refresh_interval) => refresh_interval,
+            None => return false,
+        };
+
+        let mut refreshed = self.refreshed.lock().unwrap();
+
+        match refreshed.elapsed() >= refresh_interval {
+            true => {
+                *refreshed = Instant::now();
+                true
+            }
+            false => false,
+        }
+    }
+}
+
+impl Runner {
+    pub async fn refresh_servers(&self) -> Result<(), Error> {
+        let (_, scheme, authority) = self.servers.current();
+
+        if scheme != Scheme::HTTP {
+            return Ok(());
+        }
+
+        let uri = Uri::builder()
+            .scheme(scheme.clone())
+            .authority(authority.clone())
+            .path_and_query(STATUS_PEERS_PATH)
+            .build()?;
+        let request = self
+            .build_request(Method::GET, uri, Body::empty(), None)
+            .await?;
+        let (parts, body) = request.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+        let response = self.send_failover(&parts, &body).await?;
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let peers: Vec<String> = serde_json::from_slice(&bytes)?;
+
+        let mut servers = Vec::with_capacity(peers.len());
+
+        for peer in peers {
+            let host = match peer.rsplit_once(':') {
+                Some((host, _)) => host,
+                None => peer.as_str(),
+            };
+
+            let address = match authority.port_u16() {
+                Some(port) => format!("{}:{}", host, port),
+                None => host.to_owned(),
+            };
+
+            servers.push((scheme.clone(), Authority::from_str(&address)?));
+        }
+
+        self.servers.add_peers(servers);
+
+        Ok(())
+    }
+}
+
+pub(crate) fn apply(uri: &mut Uri, scheme: Scheme, authority: Authority) {
+    let mut parts = uri.clone().into_parts();
+    parts.scheme = Some(scheme);
+    parts.authority = Some(authority);
+
+    if let Ok(server_uri) = Uri::from_parts(parts) {
+        *uri = server_uri;
+    }
+}
+
+pub(crate) fn fails_over(error: &Error) -> bool {
+    match error {
+        Error::Status { status, body } => match *status {
+            StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => true,
+            StatusCode::INTERNAL_SERVER_ERROR => body.contains(NO_CLUSTER_LEADER),
+            _ => false,
+        },
+        Error::Timeout => true,
+        _ => is_connect(error),
+    }
+}
+
+pub(crate) fn is_connect(error: &Error) -> bool {
+    match error {
+        Error::Transport(error) => error
+            .downcast_ref::<hyper::Error>()
+            .is_some_and(hyper::Error::is_connect),
+        _ => false,
+    }
+}
+
+fn collect(servers: Vec<(Scheme, Authority)>) -> Vec<Server> {
+    servers
+        .into_iter()
+        .map(|(scheme, authority)| Server {
+            scheme,
+            authority,
+            healthy: AtomicBool::new(true),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_authorities(authorities: &[&'static str]) -> Vec<(Scheme, Authority)> {
+        authorities
+            .iter()
+            .map(|authority| (Scheme::HTTP, Authority::from_static(authority)))
+            .collect()
+    }
+
+    #[test]
+    fn failed() {
+        let test_events = Arc::new(Mutex::new(Vec::new()));
+        let test_recorded = test_events.clone();
+        let test_callback: FailoverCallback = Arc::new(move |event: &FailoverEvent<'_>| {
+            let test_event = format!("{} -> {}", event.from, event.to);
+            test_recorded.lock().unwrap().push(test_event);
+        });
+        let test_servers = Servers::new(
+            test_authorities(&["10.0.0.1:8500", "10.0.0.2:8500", "10.0.0.3:8500"]),
+            None,
+            Some(test_callback),
+        );
+        let test_error = Error::Timeout;
+        assert_eq!(test_servers.current().0, 0);
+        test_servers.failed(0, &test_error);
+        assert_eq!(test_servers.current().0, 1);
+        test_servers.failed(1, &test_error);
+        assert_eq!(test_servers.current().0, 2);
+        test_servers.succeeded(0);
+        test_servers.failed(2, &test_error);
+        assert_eq!(test_servers.current().0, 0);
+        test_servers.failed(2, &test_error);
+        assert_eq!(test_servers.current().0, 0);
+        assert_eq!(
+            test_events.lock().unwrap().as_slice(),
+            [
+                "10.0.0.1:8500 -> 10.0.0.2:8500",
+                "10.0.0.2:8500 -> 10.0.0.3:8500",
+                "10.0.0.3:8500 -> 10.0.0.1:8500",
+            ],
+        );
+    }
+
+    #[test]
+    fn refresh_due() {
+        let test_servers = Servers::new(test_authorities(&["10.0.0.1:8500"]), None, None);
+        assert!(!test_servers.refresh_due());
+        let test_servers = Servers::new(
+            test_authorities(&["10.0.0.1:8500"]),
+            Some(Duration::from_secs(0)),
+            None,
+        );
+        assert!(test_servers.refresh_due());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn failover() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let test_unreachable = format!("http://{}", test_listener.local_addr().unwrap());
+        drop(test_listener);
+        let test_events = Arc::new(AtomicUsize::new(0));
+        let test_recorded = test_events.clone();
+        let test_runner = Runner::builder()
+            .addresses([test_unreachable, test_server.url()])
+            .on_failover(move |_: &FailoverEvent<'_>| {
+                test_recorded.fetch_add(1, Ordering::SeqCst);
+            })
+            .build()?;
+        let mock = test_server
+            .mock("GET", "/v1/status/leader")
+            .with_body("\"10.1.10.12:8300\"")
+            .expect(2)
+            .create_async()
+            .await;
+        test_runner.status_leader(None).await?;
+        test_runner.status_leader(None).await?;
+        mock.assert();
+        assert_eq!(test_events.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn failover_server_error() -> Result<(), Error> {
+        let mut test_first = mockito::Server::new_async().await;
+        let mut test_second = mockito::Server::new_async().await;
+        let test_runner = Runner::builder()
+            .addresses([test_first.url(), test_second.url()])
+            .build()?;
+        let test_error = test_first
+            .mock("GET", "/v1/status/leader")
+            .with_status(500)
+            .with_body("test_error")
+            .expect(1)
+            .create_async()
+            .await;
+        let test_unused = test_second
+            .mock("GET", "/v1/status/leader")
+            .expect(0)
+            .create_async()
+            .await;
+        let test_result = test_runner.status_leader(None).await;
+        assert!(matches!(
+            test_result,
+            Err(Error::Status { status, ref body }) if status == 500 && body == "test_error"
+        ));
+        let (test_index, _, _) = test_runner.servers.current();
+        assert_eq!(test_index, 0);
+        test_error.assert();
+        test_unused.assert();
+        test_error.remove();
+        test_unused.remove();
+        let test_no_leader = test_first
+            .mock("GET", "/v1/status/leader")
+            .with_status(500)
+            .with_body("No cluster leader")
+            .create_async()
+            .await;
+        let test_leader = test_second
+            .mock("GET", "/v1/status/leader")
+            .with_body("\"10.1.10.12:8300\"")
+            .create_async()
+            .await;
+        test_runner.status_leader(None).await?;
+        let (test_index, _, _) = test_runner.servers.current();
+        assert_eq!(test_index, 1);
+        test_no_leader.assert();
+        test_leader.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn refresh_servers() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::builder().address(test_server.url()).build()?;
+        let test_port = test_runner.authority.port_u16().unwrap();
+        let mock = test_server
+            .mock("GET", "/v1/status/peers")
+            .with_body("[\"10.1.10.12:8300\",\"10.1.10.11:8300\"]")
+            .expect(2)
+            .create_async()
+            .await;
+        test_runner.refresh_servers().await?;
+        test_runner.refresh_servers().await?;
+        mock.assert();
+        assert_eq!(test_runner.servers.len(), 3);
+        let (test_index, test_scheme, test_authority) = test_runner.servers.current();
+        assert_eq!(test_index, 0);
+        assert_eq!(test_scheme, Scheme::HTTP);
+        assert_eq!(test_authority, test_runner.authority);
+        let test_servers = test_runner.servers.servers.read().unwrap().clone();
+        assert_eq!(
+            test_servers[1].authority,
+            format!("10.1.10.12:{}", test_port).as_str(),
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn refresh_servers_invalid() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::builder().address(test_server.url()).build()?;
+        let mock = test_server
+            .mock("GET", "/v1/status/peers")
+            .with_body("not_peers")
+            .create_async()
+            .await;
+        let test_error = test_runner.refresh_servers().await.unwrap_err();
+        mock.assert();
+        assert!(matches!(test_error, Error::Decode(_)));
+        assert_eq!(test_runner.servers.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn refresh_servers_https() -> Result<(), Error> {
+        let test_runner = Runner::builder()
+            .address("https://consul.example.com:8501")
+            .build()?;
+        test_runner.refresh_servers().await?;
+        assert_eq!(test_runner.servers.len(), 1);
+        let (_, test_scheme, test_authority) = test_runner.servers.current();
+        assert_eq!(test_scheme, Scheme::HTTPS);
+        assert_eq!(test_authority, "consul.example.com:8501");
+        Ok(())
+    }
+}
@@ -0,0 +1,274 @@
+use hyper::{Body, Request, Response};
+
+#[cfg(feature = "gzip")]
+use flate2::write::GzDecoder;
+#[cfg(feature = "gzip")]
+use futures_core::Stream;
+#[cfg(feature = "gzip")]
+use hyper::body::{Bytes, HttpBody};
+
+#[cfg(feature = "gzip")]
+use std::io::Write;
+#[cfg(feature = "gzip")]
+use std::pin::Pin;
+#[cfg(feature = "gzip")]
+use std::task::{ready, Context, Poll};
+
+#[cfg(feature = "gzip")]
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[cfg(feature = "gzip")]
+pub(crate) fn accept(request: &mut Request<Body>) {
+    request
+        .headers_mut()
+        .entry(http::header::ACCEPT_ENCODING)
+        .or_insert(http::HeaderValue::from_static("gzip"));
+}
+
+#[cfg(not(feature = "gzip"))]
+pub(crate) fn accept(_: &mut Request<Body>) {}
+
+#[cfg(feature = "gzip")]
+pub(crate) fn decompress(response: Response<Body>) -> Response<Body> {
+    let gzipped = response
+        .headers()
+        .get(http::header::CONTENT_ENCODING)
+        .and_then(|encoding| encoding.to_str().ok())
+        .is_some_and(|encoding| encoding.trim().eq_ignore_ascii_case("gzip"));
+
+    if !gzipped {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+
+    parts.headers.remove(http::header::CONTENT_ENCODING);
+    parts.headers.remove(http::header::CONTENT_LENGTH);
+
+    let stream = GzipStream {
+        body,
+        decoder: Some(GzDecoder::new(Vec::new())),
+    };
+
+    Response::from_parts(parts, Body::wrap_stream(stream))
+}
+
+#[cfg(not(feature = "gzip"))]
+pub(crate) fn decompress(response: Response<Body>) -> Response<Body> {
+    response
+}
+
+#[cfg(feature = "gzip")]
+struct GzipStream {
+    body: Body,
+    decoder: Option<GzDecoder<Vec<u8>>>,
+}
+
+#[cfg(feature = "gzip")]
+impl Stream for GzipStream {
+    type Item = Result<Bytes, BoxError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let stream = &mut *self;
+
+        loop {
+            let decoder = match &mut stream.decoder {
+                Some(decoder) => decoder,
+                None => return Poll::Ready(None),
+            };
+
+            match ready!(Pin::new(&mut stream.body).poll_data(context)) {
+                Some(Ok(chunk)) => {
+                    if let Err(error) = decoder.write_all(&chunk) {
+                        stream.decoder = None;
+                        return Poll::Ready(Some(Err(error.into())));
+                    }
+
+                    let decoded = std::mem::take(decoder.get_mut());
+
+                    if !decoded.is_empty() {
+                        return Poll::Ready(Some(Ok(Bytes::from(decoded))));
+                    }
+                }
+                Some(Err(error)) => {
+                    stream.decoder = None;
+                    return Poll::Ready(Some(Err(error.into())));
+                }
+                None => {
+                    let decoder = stream.decoder.take();
+                    let finished = decoder.map(|decoder| decoder.finish()).transpose();
+
+                    return match finished {
+                        Ok(Some(decoded)) if !decoded.is_empty() => {
+                            Poll::Ready(Some(Ok(Bytes::from(decoded))))
+                        }
+                        Ok(_) => Poll::Ready(None),
+                        Err(error) => Poll::Ready(Some(Err(error.into()))),
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "gzip"))]
+mod tests {
+    use super::*;
+    use crate::{Error, Runner};
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io;
+
+    fn gzip(test_body: &str) -> Vec<u8> {
+        let mut test_encoder = GzEncoder::new(Vec::new(), Compression::default());
+        test_encoder.write_all(test_body.as_bytes()).unwrap();
+        test_encoder.finish().unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn decompress() -> Result<(), Error> {
+        let test_body = "[{\"Key\":\"test_key\"}]".repeat(512);
+        let test_gzipped = gzip(&test_body);
+        let test_chunks: Vec<Result<Vec<u8>, io::Error>> = test_gzipped
+            .chunks(16)
+            .map(|test_chunk| Ok(test_chunk.to_vec()))
+            .collect();
+        let test_stream = Body::wrap_stream(TestChunks(test_chunks.into_iter()));
+        let test_response = Response::builder()
+            .header("content-encoding", "gzip")
+            .header("content-length", test_gzipped.len())
+            .body(test_stream)?;
+        let test_response = super::decompress(test_response);
+        assert!(test_response.headers().get("content-encoding").is_none());
+        assert!(test_response.headers().get("content-length").is_none());
+        let test_decoded = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_decoded, test_body);
+        let test_response = super::decompress(Response::new(Body::from("test_plain")));
+        let test_decoded = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_decoded, "test_plain");
+        let test_response = Response::builder()
+            .header("content-encoding", "gzip")
+            .body(Body::from("test_invalid"))?;
+        let test_response = super::decompress(test_response);
+        assert!(hyper::body::to_bytes(test_response.into_body())
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    struct TestChunks(std::vec::IntoIter<Result<Vec<u8>, io::Error>>);
+
+    impl Stream for TestChunks {
+        type Item = Result<Vec<u8>, io::Error>;
+
+        fn poll_next(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            Poll::Ready(self.0.next())
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn catalog_services() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::init(&test_server.url(), None, None).await?;
+        let test_body = "{\"consul\":[],\"test_service\":[\"test_tag\"]}";
+        let mock = test_server
+            .mock("GET", "/v1/catalog/services")
+            .match_header("accept-encoding", "gzip")
+            .with_header("content-encoding", "gzip")
+            .with_header("x-consul-index", "42")
+            .with_body(gzip(test_body))
+            .create_async()
+            .await;
+        let (test_response, test_meta) = test_runner.catalog_services(None).await?;
+        mock.assert();
+        assert_eq!(test_meta.last_index, 42);
+        let test_decoded = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_decoded, test_body);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn health_state() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::init(&test_server.url(), None, None).await?;
+        let test_body = "[{\"CheckID\":\"serfHealth\",\"Status\":\"passing\"}]";
+        let mock = test_server
+            .mock("GET", "/v1/health/state/any")
+            .match_header("accept-encoding", "gzip")
+            .with_header("content-encoding", "gzip")
+            .with_body(gzip(test_body))
+            .create_async()
+            .await;
+        let (test_response, _) = test_runner.health_state("any", None).await?;
+        mock.assert();
+        let test_decoded = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_decoded, test_body);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn key_list() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::init(&test_server.url(), None, None).await?;
+        let test_pair = "{\"Key\":\"test_prefix/test_key\",\"Value\":\"dGVzdA==\"}";
+        let test_body = format!("[{}]", vec![test_pair; 64].join(","));
+        let mock = test_server
+            .mock("GET", "/v1/kv/test_prefix")
+            .match_query("recurse=true")
+            .match_header("accept-encoding", "gzip")
+            .with_header("content-encoding", "gzip")
+            .with_body(gzip(&test_body))
+            .create_async()
+            .await;
+        let (test_pairs, _) = test_runner.key_list("test_prefix", None).await?;
+        mock.assert();
+        assert_eq!(test_pairs.len(), 64);
+        assert_eq!(test_pairs[63].value_str()?, Some("test"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn accept() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::init(&test_server.url(), None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/agent/self")
+            .match_header("accept-encoding", "identity")
+            .with_body("{}")
+            .create_async()
+            .await;
+        let mut test_headers = crate::HeaderMap::new();
+        test_headers.insert("accept-encoding", "identity".parse()?);
+        let (test_response, _) = test_runner
+            .request(
+                crate::Method::GET,
+                "/v1/agent/self",
+                &[],
+                &test_headers,
+                Body::empty(),
+                None,
+            )
+            .await?;
+        mock.assert();
+        let test_decoded = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_decoded, "{}");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn status_error() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::init(&test_server.url(), None, None).await?;
+        let mock = test_server
+            .mock("GET", "/v1/status/leader")
+            .with_status(500)
+            .with_header("content-encoding", "gzip")
+            .with_body(gzip("test_error"))
+            .create_async()
+            .await;
+        let test_error = test_runner.status_leader(None).await.unwrap_err();
+        mock.assert();
+        assert!(matches!(test_error, Error::Status { body, .. } if body == "test_error"));
+        Ok(())
+    }
+}
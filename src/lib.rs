@@ -1,74 +1,129 @@
 use http::uri::{Authority, Builder, Scheme};
-use hyper::client::connect::HttpConnector;
-use hyper::header::{HeaderName, HeaderValue};
-use hyper::{Body, Client, Error, HeaderMap, Method, Request, Response, Uri};
-use hyper_tls::HttpsConnector;
+use hyper::body::Bytes;
+use hyper::http::request::Parts;
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode, Uri};
 
-use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod engine;
 
+mod builder;
+mod endpoint;
+mod env;
+mod error;
+mod failover;
+mod gzip;
+mod limit;
+mod meta;
+mod proxy;
+mod query;
+mod recorder;
+mod resolve;
+mod retry;
+mod tls;
+mod trace;
+mod transport;
+#[cfg(unix)]
+mod unix;
+
+pub use builder::RunnerBuilder;
+pub use endpoint::EndpointGroup;
+pub use error::{Error, ErrorKind};
+pub use failover::FailoverEvent;
+pub use limit::Limits;
+pub use meta::QueryMeta;
+pub use query::{Consistency, QueryOptions, WaitOptions};
+#[cfg(feature = "metrics")]
+pub use recorder::MetricsFacade;
+pub use recorder::{MetricsRecorder, Outcome};
+pub use resolve::{ResolveFuture, Resolver};
+pub use retry::RetryPolicy;
+
+use endpoint::Endpoint;
+use failover::Servers;
+use limit::Limiters;
+use query::{AllowNotFound, Blocking};
+use recorder::InFlight;
+use retry::{Idempotent, Streaming};
+pub use tls::{TlsConfig, TlsConnector};
+pub use transport::{HyperTransport, ServiceTransport, Transport, TransportFuture};
+#[cfg(unix)]
+pub use unix::{UnixConnection, UnixConnector};
+
+#[derive(Clone)]
 pub struct Runner {
-    pub client: Client<HttpsConnector<HttpConnector>, Body>,
+    transport: Arc<dyn Transport>,
     pub endpoint: Uri,
     default_headers: HeaderMap,
     scheme: Scheme,
     authority: Authority,
+    datacenter: Option<String>,
+    namespace: Option<String>,
+    partition: Option<String>,
+    request_timeout: Option<Duration>,
+    retry: Option<RetryPolicy>,
+    servers: Arc<Servers>,
+    metrics: Option<Arc<dyn MetricsRecorder>>,
+    limiters: Arc<Limiters>,
 }
 
 impl Runner {
-    pub async fn init(uri: &str, header_name: Option<&str>, header_value: Option<&str>) -> Runner {
-        let https = HttpsConnector::new();
-        let client = Client::builder().build(https);
-        let endpoint = Uri::from_str(uri).unwrap();
-        let mut default_headers = HeaderMap::new();
-
-        default_headers.reserve(5);
+    pub fn builder() -> RunnerBuilder {
+        RunnerBuilder::new()
+    }
 
-        let user_agent_name = http::header::USER_AGENT;
-        let user_agent_value = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    pub fn from_env() -> Result<Runner, Error> {
+        RunnerBuilder::from_env()?.build()
+    }
 
-        default_headers.insert(
-            user_agent_name,
-            HeaderValue::from_str(user_agent_value).unwrap(),
-        );
+    pub async fn init(
+        uri: &str,
+        header_name: Option<&str>,
+        header_value: Option<&str>,
+    ) -> Result<Runner, Error> {
+        let mut builder = Runner::builder().address(uri);
 
-        if header_name.is_some() && header_value.is_some() {
-            let header = HeaderName::from_str(header_name.unwrap()).unwrap();
-            let value = HeaderValue::from_str(header_value.unwrap());
-            if let Ok(mut value) = value {
-                value.set_sensitive(true);
-                default_headers.insert(header, value);
-            }
+        if let (Some(header_name), Some(header_value)) = (header_name, header_value) {
+            builder = builder.sensitive_header(header_name, header_value);
         }
 
-        let uri_part = endpoint.clone().into_parts();
-
-        let scheme = match uri_part.scheme {
-            Some(scheme) => scheme,
-            None => panic!("Scheme could not be set!"),
-        };
+        builder.build()
+    }
 
-        let authority = match uri_part.authority {
-            Some(authority) => authority,
-            None => panic!("Authority could not be set!"),
-        };
+    pub async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        headers: &HeaderMap,
+        body: impl Into<Body>,
+        options: Option<&QueryOptions>,
+    ) -> Result<(Response<Body>, QueryMeta), Error> {
+        let uri = self.build_uri_with_query(path, query, options).await?;
+        let body = body.into();
+        let mut request = self.build_request(method, uri, body, options).await?;
 
-        Runner {
-            client,
-            endpoint,
-            default_headers,
-            scheme,
-            authority,
+        for (name, value) in headers.iter() {
+            request.headers_mut().insert(name, value.to_owned());
         }
+
+        let endpoint = Endpoint::new("request", path);
+        let response = self.execute(&endpoint, request).await?;
+        let meta = QueryMeta::parse(response.headers())?;
+        Ok((response, meta))
     }
 
-    async fn build_request(&self, method: Method, uri: Uri, body: Body) -> Request<Body> {
-        let mut request = Request::builder()
-            .method(method)
-            .uri(uri)
-            .body(body)
-            .expect("Could not build Request!");
+    async fn build_request(
+        &self,
+        method: Method,
+        uri: Uri,
+        body: Body,
+        options: Option<&QueryOptions>,
+    ) -> Result<Request<Body>, Error> {
+        let mut request = Request::builder().method(method).uri(uri).body(body)?;
 
         let headers = request.headers_mut();
 
@@ -76,16 +131,206 @@ impl Runner {
             headers.insert(name, value.to_owned());
         }
 
-        request
+        if let Some(options) = options {
+            options.apply_headers(headers)?;
+
+            if let Some(wait) = &options.wait {
+                request.extensions_mut().insert(Blocking(wait.duration()));
+            }
+        }
+
+        Ok(request)
     }
 
-    async fn build_uri(&self, path_and_query: &str) -> Uri {
-        Builder::new()
+    async fn build_uri(&self, path: &str, options: Option<&QueryOptions>) -> Result<Uri, Error> {
+        self.build_uri_with_query(path, &[], options).await
+    }
+
+    async fn build_uri_with_query(
+        &self,
+        path: &str,
+        pairs: &[(&str, &str)],
+        options: Option<&QueryOptions>,
+    ) -> Result<Uri, Error> {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+
+        let datacenter = options.and_then(|options| options.datacenter.as_ref());
+        let namespace = options.and_then(|options| options.namespace.as_ref());
+        let partition = options.and_then(|options| options.partition.as_ref());
+
+        if let Some(datacenter) = datacenter.or(self.datacenter.as_ref()) {
+            query.append_pair("dc", datacenter);
+        }
+
+        if let Some(namespace) = namespace.or(self.namespace.as_ref()) {
+            query.append_pair("ns", namespace);
+        }
+
+        if let Some(partition) = partition.or(self.partition.as_ref()) {
+            query.append_pair("partition", partition);
+        }
+
+        for (key, value) in pairs {
+            query.append_pair(key, value);
+        }
+
+        if let Some(options) = options {
+            options.append_query(&mut query);
+        }
+
+        let query = query.finish();
+        let path_and_query = match query.is_empty() {
+            true => path.to_owned(),
+            false => format!("{}?{}", path, query),
+        };
+
+        let uri = Builder::new()
             .scheme(self.scheme.as_str())
             .authority(self.authority.as_str())
             .path_and_query(path_and_query)
-            .build()
-            .expect("Could not build URI!")
+            .build()?;
+
+        Ok(uri)
+    }
+
+    async fn execute(
+        &self,
+        endpoint: &Endpoint<'_>,
+        request: Request<Body>,
+    ) -> Result<Response<Body>, Error> {
+        let blocking = request.extensions().get::<Blocking>().is_some();
+        let _permits = self.limiters.acquire(endpoint, blocking).await;
+        let span = trace::span(endpoint, &request);
+        let in_flight = InFlight::start(self.metrics.as_deref(), endpoint.name);
+        let result = trace::instrument(span, self.dispatch(request)).await;
+
+        in_flight.finish(&result);
+        result
+    }
+
+    async fn dispatch(&self, request: Request<Body>) -> Result<Response<Body>, Error> {
+        if self.servers.refresh_due() {
+            let _ = self.refresh_servers().await;
+        }
+
+        if request.extensions().get::<Streaming>().is_some() {
+            return self.send_streaming(request).await;
+        }
+
+        let retry = self.retry.as_ref().filter(|retry| retry.allows(&request));
+
+        if retry.is_none() && self.servers.len() == 1 {
+            return self.send(request).await;
+        }
+
+        let (parts, body) = request.into_parts();
+        let body = hyper::body::to_bytes(body).await?;
+        let mut attempt = 1;
+
+        loop {
+            match (retry, self.send_failover(&parts, &body).await) {
+                (Some(retry), Err(error))
+                    if attempt < retry.max_attempts && retry.retries(&error) =>
+                {
+                    let delay = retry.delay(attempt);
+                    attempt += 1;
+                    trace::retry(attempt, delay, &error);
+                    tokio::time::sleep(delay).await;
+                }
+                (_, result) => return result,
+            }
+        }
+    }
+
+    async fn send_failover(&self, parts: &Parts, body: &Bytes) -> Result<Response<Body>, Error> {
+        let replayable = matches!(parts.method, Method::GET | Method::HEAD)
+            || parts.extensions.get::<Idempotent>().is_some();
+        let mut remaining = self.servers.len();
+
+        loop {
+            let (index, scheme, authority) = self.servers.current();
+            let mut request = retry::rebuild(parts, body.clone());
+            failover::apply(request.uri_mut(), scheme, authority.clone());
+
+            match self.send(request).await {
+                Err(error) if failover::fails_over(&error) => {
+                    trace::failover(&authority, &error);
+                    self.servers.failed(index, &error);
+                    remaining -= 1;
+
+                    if remaining == 0 || !(replayable || failover::is_connect(&error)) {
+                        return Err(error);
+                    }
+                }
+                result => {
+                    self.servers.succeeded(index);
+                    return result;
+                }
+            }
+        }
+    }
+
+    async fn send_streaming(&self, mut request: Request<Body>) -> Result<Response<Body>, Error> {
+        let (index, scheme, authority) = self.servers.current();
+        failover::apply(request.uri_mut(), scheme, authority);
+
+        let result = self.send(request).await;
+
+        match &result {
+            Err(error) if failover::fails_over(error) => self.servers.failed(index, error),
+            _ => self.servers.succeeded(index),
+        }
+
+        result
+    }
+
+    async fn send(&self, mut request: Request<Body>) -> Result<Response<Body>, Error> {
+        gzip::accept(&mut request);
+
+        let blocking = request.extensions().get::<Blocking>().copied();
+        let allow_not_found = request.extensions().get::<AllowNotFound>().is_some();
+        let request_timeout = match blocking {
+            Some(Blocking(wait)) => self
+                .request_timeout
+                .map(|timeout| timeout + wait + wait / 16),
+            None => self.request_timeout,
+        };
+
+        let response = match request_timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.transport.send(request))
+                .await
+                .map_err(|_| Error::Timeout)??,
+            None => self.transport.send(request).await?,
+        };
+
+        let response = gzip::decompress(response);
+
+        let status = response.status();
+
+        if status.is_success() || (allow_not_found && status == StatusCode::NOT_FOUND) {
+            return Ok(response);
+        }
+
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let body = String::from_utf8_lossy(&bytes).into_owned();
+
+        Err(Error::Status { status, body })
+    }
+
+    fn encode<T: serde::Serialize>(&self, payload: &T) -> Result<Body, Error> {
+        let encoded = serde_json::to_vec(payload)?;
+
+        Ok(Body::from(encoded))
+    }
+
+    async fn decode<T: serde::de::DeserializeOwned>(
+        &self,
+        response: Response<Body>,
+    ) -> Result<T, Error> {
+        let bytes = hyper::body::to_bytes(response.into_body()).await?;
+        let decoded = serde_json::from_slice(&bytes)?;
+
+        Ok(decoded)
     }
 }
 
@@ -93,9 +338,11 @@ impl Runner {
 mod tests {
     use super::*;
 
+    const TEST_ENDPOINT: Endpoint<'static> = Endpoint::new("test", "/test");
+
     #[tokio::test(flavor = "multi_thread")]
-    async fn init() {
-        let test_runner = Runner::init("http://example.com/", None, None).await;
+    async fn init() -> Result<(), Error> {
+        let test_runner = Runner::init("http://example.com/", None, None).await?;
         assert_eq!(test_runner.endpoint, "http://example.com/");
         assert_eq!(test_runner.default_headers.len(), 1);
         assert!(test_runner.default_headers.capacity() >= 5);
@@ -106,16 +353,17 @@ mod tests {
         }
         assert_eq!(test_runner.scheme.as_str(), "http");
         assert_eq!(test_runner.authority.as_str(), "example.com");
+        Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn init_with_headers() {
+    async fn init_with_headers() -> Result<(), Error> {
         let test_runner = Runner::init(
             "http://example.com/",
             Some("test_header_name"),
             Some("test_header_value"),
         )
-        .await;
+        .await?;
         assert_eq!(test_runner.endpoint, "http://example.com/");
         assert!(!test_runner.default_headers.is_empty());
         assert_eq!(test_runner.default_headers.len(), 2);
@@ -133,17 +381,38 @@ mod tests {
         );
         assert_eq!(test_runner.scheme.as_str(), "http");
         assert_eq!(test_runner.authority.as_str(), "example.com");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn init_invalid() {
+        let test_missing_scheme = Runner::init("example.com", None, None).await;
+        assert!(matches!(test_missing_scheme, Err(Error::InvalidConfig(_))));
+        let test_missing_authority = Runner::init("/v1/kv", None, None).await;
+        assert!(matches!(
+            test_missing_authority,
+            Err(Error::InvalidConfig(_))
+        ));
+        let test_invalid_uri = Runner::init("http://exa mple.com/", None, None).await;
+        assert!(matches!(test_invalid_uri, Err(Error::InvalidConfig(_))));
+        let test_invalid_header = Runner::init(
+            "http://example.com/",
+            Some("test header name"),
+            Some("test_header_value"),
+        )
+        .await;
+        assert!(matches!(test_invalid_header, Err(Error::InvalidConfig(_))));
     }
 
     #[tokio::test(flavor = "multi_thread")]
-    async fn build_request() {
-        let test_runner = Runner::init("http://example.com/", None, None).await;
+    async fn build_request() -> Result<(), Error> {
+        let test_runner = Runner::init("http://example.com/", None, None).await?;
         let test_method = Method::GET;
         let test_uri = test_runner.endpoint.clone();
         let test_body = Body::empty();
         let test_request = test_runner
-            .build_request(test_method, test_uri, test_body)
-            .await;
+            .build_request(test_method, test_uri, test_body, None)
+            .await?;
         assert_eq!(test_request.method().as_str(), "GET");
         assert_eq!(test_request.uri(), "http://example.com/");
         assert_eq!(test_request.headers().len(), 1);
@@ -151,13 +420,14 @@ mod tests {
             assert_eq!(key.as_str(), "user-agent");
             assert_eq!(value.to_str().unwrap(), "sublight/0.1.0");
         }
+        Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread")]
     async fn build_uri() -> Result<(), Error> {
-        let test_runner = Runner::init("http://example.com/", None, None).await;
+        let test_runner = Runner::init("http://example.com/", None, None).await?;
         let test_path_and_query = "/test_path_and_query";
-        let test_build_uri = test_runner.build_uri(test_path_and_query).await;
+        let test_build_uri = test_runner.build_uri(test_path_and_query, None).await?;
         let test_parts = test_build_uri.into_parts();
 
         assert_eq!(test_parts.scheme.unwrap().as_str(), "http");
@@ -168,4 +438,256 @@ mod tests {
         );
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn build_uri_defaults() -> Result<(), Error> {
+        let test_runner = Runner::builder()
+            .address("http://example.com/")
+            .datacenter("test_dc")
+            .namespace("test ns")
+            .partition("test_partition")
+            .build()?;
+        let test_build_uri = test_runner.build_uri("/v1/catalog/nodes", None).await?;
+        assert_eq!(
+            test_build_uri.path_and_query().unwrap().as_str(),
+            "/v1/catalog/nodes?dc=test_dc&ns=test+ns&partition=test_partition",
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn build_uri_options() -> Result<(), Error> {
+        let test_runner = Runner::builder()
+            .address("http://example.com/")
+            .datacenter("test_dc")
+            .namespace("test_ns")
+            .build()?;
+        let test_options = QueryOptions::new()
+            .datacenter("test_other_dc")
+            .filter("Node == \"test_node\"")
+            .stale();
+        let test_build_uri = test_runner
+            .build_uri("/v1/catalog/nodes", Some(&test_options))
+            .await?;
+        assert_eq!(
+            test_build_uri.path_and_query().unwrap().as_str(),
+            "/v1/catalog/nodes?dc=test_other_dc&ns=test_ns&filter=Node+%3D%3D+%22test_node%22&stale",
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn build_request_options() -> Result<(), Error> {
+        let test_runner = Runner::builder()
+            .address("http://example.com/")
+            .token("test_token")
+            .build()?;
+        let test_options = QueryOptions::new().token("test_override_token");
+        let test_uri = test_runner.build_uri("/", Some(&test_options)).await?;
+        let test_request = test_runner
+            .build_request(Method::GET, test_uri, Body::empty(), Some(&test_options))
+            .await?;
+        assert_eq!(
+            test_request.headers().get("x-consul-token").unwrap(),
+            "test_override_token",
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn build_request_blocking() -> Result<(), Error> {
+        let test_runner = Runner::init("http://example.com/", None, None).await?;
+        let test_wait = WaitOptions::new(42).wait(Duration::from_secs(30));
+        let test_options = QueryOptions::new().wait(test_wait);
+        let test_uri = test_runner.build_uri("/", Some(&test_options)).await?;
+        assert_eq!(test_uri.query(), Some("index=42&wait=30000ms"));
+        let test_request = test_runner
+            .build_request(Method::GET, test_uri, Body::empty(), Some(&test_options))
+            .await?;
+        let test_blocking = test_request.extensions().get::<Blocking>().unwrap();
+        assert_eq!(test_blocking.0, Duration::from_secs(30));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn execute_timeout() -> Result<(), Error> {
+        let test_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let test_address = format!("http://{}", test_listener.local_addr().unwrap());
+        let test_runner = Runner::builder()
+            .address(test_address)
+            .request_timeout(Duration::from_millis(50))
+            .build()?;
+        let test_uri = test_runner.build_uri("/test_timeout", None).await?;
+        let test_request = test_runner
+            .build_request(Method::GET, test_uri, Body::empty(), None)
+            .await?;
+        let test_error = test_runner
+            .execute(&TEST_ENDPOINT, test_request)
+            .await
+            .unwrap_err();
+        assert!(test_error.is_timeout());
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn execute_status() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_mock_url = test_server.url();
+        let test_runner = Runner::init(&test_mock_url, None, None).await?;
+        let mock = test_server
+            .mock("GET", "/test_status")
+            .with_status(500)
+            .with_body("No cluster leader")
+            .create_async()
+            .await;
+        let test_uri = test_runner.build_uri("/test_status", None).await?;
+        let test_request = test_runner
+            .build_request(Method::GET, test_uri, Body::empty(), None)
+            .await?;
+        let test_error = test_runner
+            .execute(&TEST_ENDPOINT, test_request)
+            .await
+            .unwrap_err();
+        mock.assert();
+        match test_error {
+            Error::Status { status, body } => {
+                assert_eq!(status.as_u16(), 500);
+                assert_eq!(body, "No cluster leader");
+            }
+            _ => panic!("expected status error"),
+        }
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn execute_retry() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_retry = RetryPolicy::new()
+            .max_attempts(3)
+            .base_delay(Duration::from_millis(1));
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .retry_policy(test_retry)
+            .build()?;
+        let mock = test_server
+            .mock("GET", "/test_retry")
+            .with_status(500)
+            .with_body("No cluster leader")
+            .expect(3)
+            .create_async()
+            .await;
+        let test_uri = test_runner.build_uri("/test_retry", None).await?;
+        let test_request = test_runner
+            .build_request(Method::GET, test_uri, Body::empty(), None)
+            .await?;
+        let test_error = test_runner
+            .execute(&TEST_ENDPOINT, test_request)
+            .await
+            .unwrap_err();
+        mock.assert();
+        assert_eq!(test_error.status().map(|status| status.as_u16()), Some(500));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn execute_retry_recovers() -> Result<(), Error> {
+        let test_attempts = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let test_counter = test_attempts.clone();
+        let test_transport = move |request: Request<Body>| {
+            let test_attempt = test_counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async move {
+                let test_body = hyper::body::to_bytes(request.into_body()).await?;
+                assert_eq!(test_body, "test_payload");
+                let test_status = match test_attempt {
+                    0 => 503,
+                    _ => 200,
+                };
+                Ok(Response::builder()
+                    .status(test_status)
+                    .body(Body::empty())?)
+            }
+        };
+        let test_retry = RetryPolicy::new()
+            .base_delay(Duration::from_millis(1))
+            .methods([Method::PUT]);
+        let test_runner = Runner::builder()
+            .transport(test_transport)
+            .retry_policy(test_retry)
+            .build()?;
+        let test_uri = test_runner.build_uri("/test_retry", None).await?;
+        let test_request = test_runner
+            .build_request(Method::PUT, test_uri, Body::from("test_payload"), None)
+            .await?;
+        let test_response = test_runner.execute(&TEST_ENDPOINT, test_request).await?;
+        assert_eq!(test_response.status().as_u16(), 200);
+        assert_eq!(test_attempts.load(std::sync::atomic::Ordering::SeqCst), 2);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn execute_retry_idempotent_writes() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_retry = RetryPolicy::new().base_delay(Duration::from_millis(1));
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .retry_policy(test_retry.clone())
+            .build()?;
+        let mock = test_server
+            .mock("PUT", "/v1/agent/check/pass/test_check")
+            .with_status(500)
+            .expect(1)
+            .create_async()
+            .await;
+        assert!(test_runner.agent_check_pass("test_check").await.is_err());
+        mock.assert();
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .retry_policy(test_retry.idempotent_writes(true))
+            .build()?;
+        let mock = test_server
+            .mock("PUT", "/v1/agent/check/pass/test_check")
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+        assert!(test_runner.agent_check_pass("test_check").await.is_err());
+        mock.assert();
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn request() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .token("test_token")
+            .datacenter("test_dc")
+            .build()?;
+        let mock = test_server
+            .mock("PUT", "/v1/internal/ui/test_endpoint")
+            .match_query("dc=test_dc&test_key=test+value&stale")
+            .match_header("x-consul-token", "test_token")
+            .match_header("test_header", "test_header_value")
+            .match_body("test_body")
+            .with_header("x-consul-index", "42")
+            .create_async()
+            .await;
+        let mut test_headers = HeaderMap::new();
+        test_headers.insert("test_header", "test_header_value".parse().unwrap());
+        let test_options = QueryOptions::new().stale();
+        let (test_response, test_meta) = test_runner
+            .request(
+                Method::PUT,
+                "/v1/internal/ui/test_endpoint",
+                &[("test_key", "test value")],
+                &test_headers,
+                "test_body",
+                Some(&test_options),
+            )
+            .await?;
+        mock.assert();
+        assert_eq!(test_response.status().as_u16(), 200);
+        assert_eq!(test_meta.last_index, 42);
+        Ok(())
+    }
 }
@@ -0,0 +1,306 @@
+use crate::{Endpoint, EndpointGroup, Error};
+
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Limits {
+    pub max_in_flight: Option<usize>,
+    pub max_blocking: Option<usize>,
+    pub rate: Option<f64>,
+    pub burst: u32,
+}
+
+impl Limits {
+    pub fn new() -> Limits {
+        Limits::default()
+    }
+
+    pub fn max_in_flight(mut self, max_in_flight: usize) -> Limits {
+        self.max_in_flight = Some(max_in_flight);
+        self
+    }
+
+    pub fn max_blocking(mut self, max_blocking: usize) -> Limits {
+        self.max_blocking = Some(max_blocking);
+        self
+    }
+
+    pub fn rate_limit(mut self, rate: f64, burst: u32) -> Limits {
+        self.rate = Some(rate);
+        self.burst = burst;
+        self
+    }
+}
+
+struct Bucket {
+    rate: f64,
+    burst: f64,
+    state: Mutex<(f64, Instant)>,
+}
+
+impl Bucket {
+    fn reserve(&self) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap();
+        let (tokens, updated) = *state;
+        let now = Instant::now();
+        let elapsed = now.duration_since(updated).as_secs_f64();
+        let tokens = (tokens + elapsed * self.rate).min(self.burst) - 1.0;
+
+        *state = (tokens, now);
+
+        match tokens < 0.0 {
+            true => Some(Duration::from_secs_f64(-tokens / self.rate)),
+            false => None,
+        }
+    }
+}
+
+struct Limiter {
+    semaphore: Option<Semaphore>,
+    blocking: Option<Semaphore>,
+    bucket: Option<Bucket>,
+}
+
+struct LimiterPermit<'a> {
+    _blocking: Option<SemaphorePermit<'a>>,
+    _in_flight: Option<SemaphorePermit<'a>>,
+}
+
+impl Limiter {
+    fn new(limits: &Limits) -> Result<Limiter, Error> {
+        let semaphore = new_semaphore(limits.max_in_flight, "max in-flight requests")?;
+        let blocking = new_semaphore(limits.max_blocking, "max blocking queries")?;
+
+        let bucket = match limits.rate {
+            Some(rate) if !rate.is_finite() || rate <= 0.0 => {
+                let reason = format!("rate limit {} must be a positive number", rate);
+                return Err(Error::InvalidConfig(reason));
+            }
+            Some(rate) => {
+                let burst = f64::from(limits.burst.max(1));
+
+                Some(Bucket {
+                    rate,
+                    burst,
+                    state: Mutex::new((burst, Instant::now())),
+                })
+            }
+            None => None,
+        };
+
+        Ok(Limiter {
+            semaphore,
+            blocking,
+            bucket,
+        })
+    }
+
+    async fn acquire(&self, blocking: bool) -> LimiterPermit<'_> {
+        if let Some(wait) = self.bucket.as_ref().and_then(Bucket::reserve) {
+            tokio::time::sleep(wait).await;
+        }
+
+        let blocking = match &self.blocking {
+            Some(semaphore) if blocking => semaphore.acquire().await.ok(),
+            _ => None,
+        };
+
+        let in_flight = match &self.semaphore {
+            Some(semaphore) => semaphore.acquire().await.ok(),
+            None => None,
+        };
+
+        LimiterPermit {
+            _blocking: blocking,
+            _in_flight: in_flight,
+        }
+    }
+}
+
+fn new_semaphore(permits: Option<usize>, what: &str) -> Result<Option<Semaphore>, Error> {
+    match permits {
+        Some(0) => {
+            let reason = format!("{} must be greater than zero", what);
+            Err(Error::InvalidConfig(reason))
+        }
+        Some(permits) => Ok(Some(Semaphore::new(permits.min(Semaphore::MAX_PERMITS)))),
+        None => Ok(None),
+    }
+}
+
+pub(crate) struct Limiters {
+    global: Limiter,
+    groups: Vec<(EndpointGroup, Limiter)>,
+}
+
+pub(crate) struct Permits<'a> {
+    _group: Option<LimiterPermit<'a>>,
+    _global: LimiterPermit<'a>,
+}
+
+impl Limiters {
+    pub(crate) fn new(
+        global: &Limits,
+        groups: &[(EndpointGroup, Limits)],
+    ) -> Result<Limiters, Error> {
+        let mut limiters = Limiters {
+            global: Limiter::new(global)?,
+            groups: Vec::with_capacity(groups.len()),
+        };
+
+        for (group, limits) in groups {
+            limiters.groups.retain(|(existing, _)| existing != group);
+            limiters.groups.push((*group, Limiter::new(limits)?));
+        }
+
+        Ok(limiters)
+    }
+
+    pub(crate) async fn acquire(&self, endpoint: &Endpoint<'_>, blocking: bool) -> Permits<'_> {
+        let group = endpoint.group().and_then(|group| {
+            self.groups
+                .iter()
+                .find(|(existing, _)| *existing == group)
+                .map(|(_, limiter)| limiter)
+        });
+
+        let group = match group {
+            Some(limiter) => Some(limiter.acquire(blocking).await),
+            None => None,
+        };
+
+        Permits {
+            _group: group,
+            _global: self.global.acquire(blocking).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QueryOptions, Runner, WaitOptions};
+    use hyper::{Body, Request, Response};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn limits() {
+        assert!(Limiters::new(&Limits::new(), &[]).is_ok());
+        let test_limits = Limits::new().max_in_flight(0);
+        assert!(Limiters::new(&test_limits, &[]).is_err());
+        let test_limits = Limits::new().rate_limit(0.0, 1);
+        assert!(Limiters::new(&Limits::new(), &[(EndpointGroup::Kv, test_limits)]).is_err());
+        let test_limits = Limits::new().max_blocking(0);
+        assert!(Limiters::new(&test_limits, &[]).is_err());
+        let test_limits = Limits::new().rate_limit(f64::NAN, 1);
+        assert!(Limiters::new(&test_limits, &[]).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn rate_limit() {
+        let test_limits = Limits::new().rate_limit(100.0, 2);
+        let test_limiters = Limiters::new(&test_limits, &[]).unwrap();
+        let test_endpoint = Endpoint::new("test", "/test");
+        let test_started = Instant::now();
+        for _ in 0..6 {
+            test_limiters.acquire(&test_endpoint, false).await;
+        }
+        assert!(test_started.elapsed() >= Duration::from_millis(35));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn max_in_flight() -> Result<(), Error> {
+        let test_active = Arc::new(AtomicUsize::new(0));
+        let test_peak = Arc::new(AtomicUsize::new(0));
+        let test_counters = (test_active.clone(), test_peak.clone());
+        let test_transport = move |_: Request<Body>| {
+            let (test_active, test_peak) = test_counters.clone();
+            async move {
+                let test_current = test_active.fetch_add(1, Ordering::SeqCst) + 1;
+                test_peak.fetch_max(test_current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                test_active.fetch_sub(1, Ordering::SeqCst);
+                Ok(Response::new(Body::from("[]")))
+            }
+        };
+        let test_runner = Runner::builder()
+            .max_in_flight(3)
+            .group_limits(EndpointGroup::Kv, Limits::new().max_in_flight(1))
+            .transport(test_transport)
+            .build()?;
+        let (test_first, test_second, test_third) = tokio::join!(
+            test_runner.key_get("test_key", None),
+            test_runner.key_get("test_key", None),
+            test_runner.key_get("test_key", None),
+        );
+        test_first?;
+        test_second?;
+        test_third?;
+        assert_eq!(test_peak.load(Ordering::SeqCst), 1);
+        test_peak.store(0, Ordering::SeqCst);
+        let (test_first, test_second, test_third, test_fourth) = tokio::join!(
+            test_runner.status_leader(None),
+            test_runner.status_leader(None),
+            test_runner.status_leader(None),
+            test_runner.status_leader(None),
+        );
+        test_first?;
+        test_second?;
+        test_third?;
+        test_fourth?;
+        assert_eq!(test_peak.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn max_in_flight_blocking() -> Result<(), Error> {
+        let test_active = Arc::new(AtomicUsize::new(0));
+        let test_peak = Arc::new(AtomicUsize::new(0));
+        let test_counters = (test_active.clone(), test_peak.clone());
+        let test_transport = move |_: Request<Body>| {
+            let (test_active, test_peak) = test_counters.clone();
+            async move {
+                let test_current = test_active.fetch_add(1, Ordering::SeqCst) + 1;
+                test_peak.fetch_max(test_current, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                test_active.fetch_sub(1, Ordering::SeqCst);
+                Ok(Response::new(Body::from("[]")))
+            }
+        };
+        let test_runner = Runner::builder()
+            .max_in_flight(1)
+            .transport(test_transport.clone())
+            .build()?;
+        let test_options = QueryOptions::new().wait(WaitOptions::new(42));
+        let (test_first, test_second, test_third) = tokio::join!(
+            test_runner.key_get("test_key", Some(&test_options)),
+            test_runner.key_get("test_key", Some(&test_options)),
+            test_runner.status_leader(None),
+        );
+        test_first?;
+        test_second?;
+        test_third?;
+        assert_eq!(test_peak.load(Ordering::SeqCst), 1);
+        test_peak.store(0, Ordering::SeqCst);
+        let test_runner = Runner::builder()
+            .limits(Limits::new().max_in_flight(3).max_blocking(1))
+            .transport(test_transport)
+            .build()?;
+        let (test_first, test_second, test_third, test_fourth) = tokio::join!(
+            test_runner.key_get("test_key", Some(&test_options)),
+            test_runner.key_get("test_key", Some(&test_options)),
+            test_runner.key_get("test_key", Some(&test_options)),
+            test_runner.status_leader(None),
+        );
+        test_first?;
+        test_second?;
+        test_third?;
+        test_fourth?;
+        assert_eq!(test_peak.load(Ordering::SeqCst), 2);
+        Ok(())
+    }
+}
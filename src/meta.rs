@@ -0,0 +1,116 @@
+use crate::Error;
+
+use hyper::HeaderMap;
+
+use std::str::FromStr;
+use std::time::Duration;
+
+const INDEX_HEADER: &str = "x-consul-index";
+const KNOWN_LEADER_HEADER: &str = "x-consul-knownleader";
+const LAST_CONTACT_HEADER: &str = "x-consul-lastcontact";
+const EFFECTIVE_CONSISTENCY_HEADER: &str = "x-consul-effective-consistency";
+const CACHE_HEADER: &str = "x-cache";
+const AGE_HEADER: &str = "age";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryMeta {
+    pub last_index: u64,
+    pub known_leader: bool,
+    pub last_contact: Duration,
+    pub effective_consistency: Option<String>,
+    pub cache_hit: Option<bool>,
+    pub cache_age: Option<Duration>,
+}
+
+impl QueryMeta {
+    pub fn parse(headers: &HeaderMap) -> Result<QueryMeta, Error> {
+        let mut meta = QueryMeta::default();
+
+        if let Some(index) = header::<u64>(headers, INDEX_HEADER)? {
+            meta.last_index = index;
+        }
+
+        if let Some(known_leader) = header::<bool>(headers, KNOWN_LEADER_HEADER)? {
+            meta.known_leader = known_leader;
+        }
+
+        if let Some(last_contact) = header::<u64>(headers, LAST_CONTACT_HEADER)? {
+            meta.last_contact = Duration::from_millis(last_contact);
+        }
+
+        meta.effective_consistency = header::<String>(headers, EFFECTIVE_CONSISTENCY_HEADER)?;
+
+        if let Some(cache) = header::<String>(headers, CACHE_HEADER)? {
+            meta.cache_hit = Some(cache.eq_ignore_ascii_case("HIT"));
+        }
+
+        if let Some(age) = header::<u64>(headers, AGE_HEADER)? {
+            meta.cache_age = Some(Duration::from_secs(age));
+        }
+
+        Ok(meta)
+    }
+}
+
+fn header<T: FromStr>(headers: &HeaderMap, name: &str) -> Result<Option<T>, Error> {
+    let value = match headers.get(name) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    let parsed = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.parse::<T>().ok());
+
+    match parsed {
+        Some(parsed) => Ok(Some(parsed)),
+        None => {
+            let reason = format!("invalid {} header {:?}", name, value);
+            Err(Error::Decode(reason.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hyper::header::HeaderValue;
+
+    #[test]
+    fn parse() -> Result<(), Error> {
+        let mut test_headers = HeaderMap::new();
+        test_headers.insert("X-Consul-Index", HeaderValue::from_static("42"));
+        test_headers.insert("X-Consul-KnownLeader", HeaderValue::from_static("true"));
+        test_headers.insert("X-Consul-LastContact", HeaderValue::from_static("150"));
+        test_headers.insert(
+            "X-Consul-Effective-Consistency",
+            HeaderValue::from_static("leader"),
+        );
+        test_headers.insert("X-Cache", HeaderValue::from_static("HIT"));
+        test_headers.insert("Age", HeaderValue::from_static("12"));
+        let test_meta = QueryMeta::parse(&test_headers)?;
+        assert_eq!(test_meta.last_index, 42);
+        assert!(test_meta.known_leader);
+        assert_eq!(test_meta.last_contact, Duration::from_millis(150));
+        assert_eq!(test_meta.effective_consistency.as_deref(), Some("leader"));
+        assert_eq!(test_meta.cache_hit, Some(true));
+        assert_eq!(test_meta.cache_age, Some(Duration::from_secs(12)));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_empty() -> Result<(), Error> {
+        let test_meta = QueryMeta::parse(&HeaderMap::new())?;
+        assert_eq!(test_meta, QueryMeta::default());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let mut test_headers = HeaderMap::new();
+        test_headers.insert("X-Consul-Index", HeaderValue::from_static("not_an_index"));
+        let test_meta = QueryMeta::parse(&test_headers);
+        assert!(matches!(test_meta, Err(Error::Decode(_))));
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{Error, QueryMeta};
+
+use hyper::header::{HeaderValue, CACHE_CONTROL};
+use hyper::HeaderMap;
+
+use std::time::Duration;
+
+type Serializer = form_urlencoded::Serializer<'static, String>;
+
+pub(crate) const TOKEN_HEADER: &str = "x-consul-token";
+
+const DEFAULT_WAIT: Duration = Duration::from_secs(300);
+const MAX_WAIT: Duration = Duration::from_secs(600);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Consistency {
+    #[default]
+    Default,
+    Stale,
+    Consistent,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WaitOptions {
+    pub index: u64,
+    pub wait: Option<Duration>,
+}
+
+impl WaitOptions {
+    pub fn new(index: u64) -> WaitOptions {
+        WaitOptions { index, wait: None }
+    }
+
+    pub fn wait(mut self, wait: Duration) -> WaitOptions {
+        self.wait = Some(wait);
+        self
+    }
+
+    pub fn advance(&mut self, meta: &QueryMeta) -> bool {
+        let previous = self.index;
+
+        self.index = match meta.last_index {
+            0 => 1,
+            index if index < previous => 0,
+            index => index,
+        };
+
+        self.index != previous
+    }
+
+    pub(crate) fn duration(&self) -> Duration {
+        self.wait.unwrap_or(DEFAULT_WAIT).min(MAX_WAIT)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Blocking(pub(crate) Duration);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AllowNotFound;
+
+#[derive(Clone, Debug, Default)]
+pub struct QueryOptions {
+    pub datacenter: Option<String>,
+    pub namespace: Option<String>,
+    pub partition: Option<String>,
+    pub filter: Option<String>,
+    pub node_meta: Vec<(String, String)>,
+    pub near: Option<String>,
+    pub tag: Vec<String>,
+    pub consistency: Consistency,
+    pub cached: bool,
+    pub max_age: Option<Duration>,
+    pub token: Option<String>,
+    pub wait: Option<WaitOptions>,
+}
+
+impl QueryOptions {
+    pub fn new() -> QueryOptions {
+        QueryOptions::default()
+    }
+
+    pub fn datacenter(mut self, datacenter: impl Into<String>) -> QueryOptions {
+        self.datacenter = Some(datacenter.into());
+        self
+    }
+
+    pub fn namespace(mut self, namespace: impl Into<String>) -> QueryOptions {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    pub fn partition(mut self, partition: impl Into<String>) -> QueryOptions {
+        self.partition = Some(partition.into());
+        self
+    }
+
+    pub fn filter(mut self, filter: impl Into<String>) -> QueryOptions {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    pub fn node_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> QueryOptions {
+        self.node_meta.push((key.into(), value.into()));
+        self
+    }
+
+    pub fn near(mut self, near: impl Into<String>) -> QueryOptions {
+        self.near = Some(near.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> QueryOptions {
+        self.tag.push(tag.into());
+        self
+    }
+
+    pub fn stale(mut self) -> QueryOptions {
+        self.consistency = Consistency::Stale;
+        self
+    }
+
+    pub fn consistent(mut self) -> QueryOptions {
+        self.consistency = Consistency::Consistent;
+        self
+    }
+
+    pub fn cached(mut self) -> QueryOptions {
+        self.cached = true;
+        self
+    }
+
+    pub fn max_age(mut self, max_age: Duration) -> QueryOptions {
+        self.cached = true;
+        self.max_age = Some(max_age);
+        self
+    }
+
+    pub fn token(mut self, token: impl Into<String>) -> QueryOptions {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn wait(mut self, wait: WaitOptions) -> QueryOptions {
+        self.wait = Some(wait);
+        self
+    }
+
+    pub(crate) fn append_query(&self, query: &mut Serializer) {
+        if let Some(filter) = &self.filter {
+            query.append_pair("filter", filter);
+        }
+
+        for (key, value) in &self.node_meta {
+            query.append_pair("node-meta", &format!("{}:{}", key, value));
+        }
+
+        if let Some(near) = &self.near {
+            query.append_pair("near", near);
+        }
+
+        for tag in &self.tag {
+            query.append_pair("tag", tag);
+        }
+
+        match self.consistency {
+            Consistency::Default => {}
+            Consistency::Stale => {
+                query.append_key_only("stale");
+            }
+            Consistency::Consistent => {
+                query.append_key_only("consistent");
+            }
+        }
+
+        if self.cached {
+            query.append_key_only("cached");
+        }
+
+        if let Some(wait) = &self.wait {
+            query.append_pair("index", &wait.index.to_string());
+
+            if let Some(duration) = wait.wait {
+                let duration = duration.min(MAX_WAIT).as_millis();
+                query.append_pair("wait", &format!("{}ms", duration));
+            }
+        }
+    }
+
+    pub(crate) fn apply_headers(&self, headers: &mut HeaderMap) -> Result<(), Error> {
+        if let Some(token) = &self.token {
+            let mut value = HeaderValue::from_str(token)?;
+            value.set_sensitive(true);
+            headers.insert(TOKEN_HEADER, value);
+        }
+
+        if let Some(max_age) = self.max_age {
+            let value = format!("max-age={}", max_age.as_secs());
+            headers.insert(CACHE_CONTROL, HeaderValue::from_str(&value)?);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_query() {
+        let test_options = QueryOptions::new()
+            .filter("Service.Tags contains \"primary\"")
+            .node_meta("rack", "r1")
+            .node_meta("zone", "z1")
+            .near("_agent")
+            .tag("v1")
+            .tag("v2")
+            .stale()
+            .cached();
+        let mut test_query = Serializer::new(String::new());
+        test_options.append_query(&mut test_query);
+        assert_eq!(
+            test_query.finish(),
+            "filter=Service.Tags+contains+%22primary%22&node-meta=rack%3Ar1&node-meta=zone%3Az1&near=_agent&tag=v1&tag=v2&stale&cached",
+        );
+        let test_options = QueryOptions::new().consistent();
+        let mut test_query = Serializer::new(String::new());
+        test_options.append_query(&mut test_query);
+        assert_eq!(test_query.finish(), "consistent");
+    }
+
+    #[test]
+    fn append_query_wait() {
+        let test_options = QueryOptions::new().wait(WaitOptions::new(42));
+        let mut test_query = Serializer::new(String::new());
+        test_options.append_query(&mut test_query);
+        assert_eq!(test_query.finish(), "index=42");
+        let test_wait = WaitOptions::new(42).wait(Duration::from_secs(30));
+        let test_options = QueryOptions::new().wait(test_wait);
+        let mut test_query = Serializer::new(String::new());
+        test_options.append_query(&mut test_query);
+        assert_eq!(test_query.finish(), "index=42&wait=30000ms");
+        let test_wait = WaitOptions::new(42).wait(Duration::from_secs(3600));
+        let test_options = QueryOptions::new().wait(test_wait);
+        let mut test_query = Serializer::new(String::new());
+        test_options.append_query(&mut test_query);
+        assert_eq!(test_query.finish(), "index=42&wait=600000ms");
+    }
+
+    #[test]
+    fn wait_advance() {
+        let mut test_wait = WaitOptions::new(0);
+        let test_meta = QueryMeta {
+            last_index: 10,
+            ..QueryMeta::default()
+        };
+        assert!(test_wait.advance(&test_meta));
+        assert_eq!(test_wait.index, 10);
+        assert!(!test_wait.advance(&test_meta));
+        assert_eq!(test_wait.index, 10);
+        let test_backwards = QueryMeta {
+            last_index: 5,
+            ..QueryMeta::default()
+        };
+        assert!(test_wait.advance(&test_backwards));
+        assert_eq!(test_wait.index, 0);
+        let test_zero = QueryMeta {
+            last_index: 0,
+            ..QueryMeta::default()
+        };
+        assert!(test_wait.advance(&test_zero));
+        assert_eq!(test_wait.index, 1);
+    }
+
+    #[test]
+    fn wait_duration() {
+        assert_eq!(WaitOptions::new(1).duration(), Duration::from_secs(300));
+        let test_wait = WaitOptions::new(1).wait(Duration::from_secs(30));
+        assert_eq!(test_wait.duration(), Duration::from_secs(30));
+        let test_wait = WaitOptions::new(1).wait(Duration::from_secs(3600));
+        assert_eq!(test_wait.duration(), Duration::from_secs(600));
+    }
+
+    #[test]
+    fn apply_headers() -> Result<(), Error> {
+        let test_options = QueryOptions::new()
+            .token("test_token")
+            .max_age(Duration::from_secs(30));
+        let mut test_headers = HeaderMap::new();
+        test_options.apply_headers(&mut test_headers)?;
+        assert_eq!(test_headers.len(), 2);
+        let test_token = test_headers.get("x-consul-token").unwrap();
+        assert_eq!(test_token, "test_token");
+        assert!(test_token.is_sensitive());
+        assert_eq!(test_headers.get(CACHE_CONTROL).unwrap(), "max-age=30");
+        assert!(test_options.cached);
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{Error, ErrorKind};
+
+use hyper::{Body, Response};
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub trait MetricsRecorder: Send + Sync + 'static {
+    fn request_started(&self, endpoint: &'static str);
+
+    fn request_finished(&self, endpoint: &'static str, latency: Duration, outcome: Outcome);
+}
+
+impl<T: MetricsRecorder + ?Sized> MetricsRecorder for Arc<T> {
+    fn request_started(&self, endpoint: &'static str) {
+        (**self).request_started(endpoint);
+    }
+
+    fn request_finished(&self, endpoint: &'static str, latency: Duration, outcome: Outcome) {
+        (**self).request_finished(endpoint, latency, outcome);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Success,
+    Error(ErrorKind),
+    Cancelled,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Success => "success",
+            Outcome::Error(kind) => kind.as_str(),
+            Outcome::Cancelled => "cancelled",
+        }
+    }
+}
+
+pub(crate) struct InFlight<'a> {
+    recorder: Option<&'a dyn MetricsRecorder>,
+    endpoint: &'static str,
+    started: Instant,
+    outcome: Outcome,
+}
+
+impl<'a> InFlight<'a> {
+    pub(crate) fn start(
+        recorder: Option<&'a dyn MetricsRecorder>,
+        endpoint: &'static str,
+    ) -> InFlight<'a> {
+        if let Some(recorder) = recorder {
+            recorder.request_started(endpoint);
+        }
+
+        InFlight {
+            recorder,
+            endpoint,
+            started: Instant::now(),
+            outcome: Outcome::Cancelled,
+        }
+    }
+
+    pub(crate) fn finish(mut self, result: &Result<Response<Body>, Error>) {
+        self.outcome = match result {
+            Ok(_) => Outcome::Success,
+            Err(error) => Outcome::Error(error.kind()),
+        };
+    }
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        if let Some(recorder) = self.recorder {
+            recorder.request_finished(self.endpoint, self.started.elapsed(), self.outcome);
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MetricsFacade;
+
+#[cfg(feature = "metrics")]
+impl MetricsFacade {
+    pub fn new() -> MetricsFacade {
+        MetricsFacade
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsFacade {
+    fn request_started(&self, endpoint: &'static str) {
+        ::metrics::gauge!("consul_client_requests_in_flight", "endpoint" => endpoint)
+            .increment(1.0);
+    }
+
+    fn request_finished(&self, endpoint: &'static str, latency: Duration, outcome: Outcome) {
+        ::metrics::gauge!("consul_client_requests_in_flight", "endpoint" => endpoint)
+            .decrement(1.0);
+        ::metrics::counter!(
+            "consul_client_requests_total",
+            "endpoint" => endpoint,
+            "outcome" => outcome.as_str(),
+        )
+        .increment(1);
+        ::metrics::histogram!("consul_client_request_duration_seconds", "endpoint" => endpoint)
+            .record(latency.as_secs_f64());
+
+        if outcome != Outcome::Success {
+            ::metrics::counter!(
+                "consul_client_errors_total",
+                "endpoint" => endpoint,
+                "kind" => outcome.as_str(),
+            )
+            .increment(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Runner;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct TestRecorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl MetricsRecorder for TestRecorder {
+        fn request_started(&self, endpoint: &'static str) {
+            let test_event = format!("started {}", endpoint);
+            self.events.lock().unwrap().push(test_event);
+        }
+
+        fn request_finished(&self, endpoint: &'static str, _: Duration, outcome: Outcome) {
+            let test_event = format!("finished {} {}", endpoint, outcome.as_str());
+            self.events.lock().unwrap().push(test_event);
+        }
+    }
+
+    #[test]
+    fn in_flight() {
+        let test_recorder = TestRecorder::default();
+        let test_in_flight = InFlight::start(Some(&test_recorder), "test");
+        test_in_flight.finish(&Err(Error::Timeout));
+        drop(InFlight::start(Some(&test_recorder), "test"));
+        assert_eq!(
+            test_recorder.events.lock().unwrap().as_slice(),
+            [
+                "started test",
+                "finished test timeout",
+                "started test",
+                "finished test cancelled",
+            ],
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn metrics_recorder() -> Result<(), Error> {
+        let mut test_server = mockito::Server::new_async().await;
+        let test_recorder = Arc::new(TestRecorder::default());
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .metrics_recorder(test_recorder.clone())
+            .build()?;
+        let mock = test_server
+            .mock("GET", "/v1/health/service/test_service")
+            .create_async()
+            .await;
+        test_runner.health_service("test_service", None).await?;
+        mock.assert();
+        let mock = test_server
+            .mock("GET", "/v1/status/leader")
+            .with_status(500)
+            .create_async()
+            .await;
+        assert!(test_runner.status_leader(None).await.is_err());
+        mock.assert();
+        assert_eq!(
+            test_recorder.events.lock().unwrap().as_slice(),
+            [
+                "started health_service",
+                "finished health_service success",
+                "started status_leader",
+                "finished status_leader status",
+            ],
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn metrics_facade() {
+        use ::metrics::{
+            Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata,
+            Recorder, SharedString, Unit,
+        };
+
+        #[derive(Clone, Default)]
+        struct TestFacade {
+            values: Arc<Mutex<Vec<String>>>,
+        }
+
+        struct TestMetric {
+            key: String,
+            values: Arc<Mutex<Vec<String>>>,
+        }
+
+        impl TestMetric {
+            fn push(&self, operation: &str, value: f64) {
+                let test_value = format!("{} {} {}", self.key, operation, value);
+                self.values.lock().unwrap().push(test_value);
+            }
+        }
+
+        impl CounterFn for TestMetric {
+            fn increment(&self, value: u64) {
+                self.push("increment", value as f64);
+            }
+
+            fn absolute(&self, value: u64) {
+                self.push("absolute", value as f64);
+            }
+        }
+
+        impl GaugeFn for TestMetric {
+            fn increment(&self, value: f64) {
+                self.push("increment", value);
+            }
+
+            fn decrement(&self, value: f64) {
+                self.push("decrement", value);
+            }
+
+            fn set(&self, value: f64) {
+                self.push("set", value);
+            }
+        }
+
+        impl HistogramFn for TestMetric {
+            fn record(&self, _: f64) {
+                self.push("record", 0.0);
+            }
+        }
+
+        impl TestFacade {
+            fn metric(&self, key: &Key) -> Arc<TestMetric> {
+                let test_labels: Vec<String> = key
+                    .labels()
+                    .map(|label| format!("{}={}", label.key(), label.value()))
+                    .collect();
+                Arc::new(TestMetric {
+                    key: format!("{}{{{}}}", key.name(), test_labels.join(",")),
+                    values: self.values.clone(),
+                })
+            }
+        }
+
+        impl Recorder for TestFacade {
+            fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+                Counter::from_arc(self.metric(key))
+            }
+
+            fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+                Gauge::from_arc(self.metric(key))
+            }
+
+            fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+                Histogram::from_arc(self.metric(key))
+            }
+        }
+
+        let test_facade = TestFacade::default();
+        ::metrics::with_local_recorder(&test_facade, || {
+            let test_recorder = MetricsFacade::new();
+            test_recorder.request_started("kv_get");
+            test_recorder.request_finished(
+                "kv_get",
+                Duration::from_millis(5),
+                Outcome::Error(ErrorKind::Transport),
+            );
+        });
+        assert_eq!(
+            test_facade.values.lock().unwrap().as_slice(),
+            [
+                "consul_client_requests_in_flight{endpoint=kv_get} increment 1",
+                "consul_client_requests_in_flight{endpoint=kv_get} decrement 1",
+                "consul_client_requests_total{endpoint=kv_get,outcome=transport} increment 1",
+                "consul_client_request_duration_seconds{endpoint=kv_get} record 0",
+                "consul_client_errors_total{endpoint=kv_get,kind=transport} increment 1",
+            ],
+        );
+    }
+}
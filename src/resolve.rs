@@ -0,0 +1,114 @@
+use hyper::client::connect::dns::{GaiResolver, Name};
+use hyper::service::Service;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+pub type ResolveFuture = Pin<Box<dyn Future<Output = io::Result<Vec<SocketAddr>>> + Send>>;
+
+pub trait Resolver: Send + Sync + 'static {
+    fn resolve(&self, host: &str) -> ResolveFuture;
+}
+
+impl<F, Fut> Resolver for F
+where
+    F: Fn(&str) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = io::Result<Vec<SocketAddr>>> + Send + 'static,
+{
+    fn resolve(&self, host: &str) -> ResolveFuture {
+        Box::pin(self(host))
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct DnsResolver {
+    overrides: Arc<HashMap<String, Vec<IpAddr>>>,
+    resolver: Option<Arc<dyn Resolver>>,
+}
+
+impl DnsResolver {
+    pub(crate) fn new(
+        overrides: &[(String, IpAddr)],
+        resolver: Option<Arc<dyn Resolver>>,
+    ) -> DnsResolver {
+        let mut hosts: HashMap<String, Vec<IpAddr>> = HashMap::with_capacity(overrides.len());
+
+        for (host, address) in overrides {
+            hosts
+                .entry(host.to_ascii_lowercase())
+                .or_default()
+                .push(*address);
+        }
+
+        DnsResolver {
+            overrides: Arc::new(hosts),
+            resolver,
+        }
+    }
+}
+
+impl Service<Name> for DnsResolver {
+    type Response = std::vec::IntoIter<SocketAddr>;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        if let Some(addresses) = self.overrides.get(&name.as_str().to_ascii_lowercase()) {
+            let addresses: Vec<SocketAddr> = addresses
+                .iter()
+                .map(|address| SocketAddr::new(*address, 0))
+                .collect();
+
+            return Box::pin(async move { Ok(addresses.into_iter()) });
+        }
+
+        match &self.resolver {
+            Some(resolver) => {
+                let resolving = resolver.resolve(name.as_str());
+
+                Box::pin(async move { Ok(resolving.await?.into_iter()) })
+            }
+            None => {
+                let resolving = GaiResolver::new().call(name);
+
+                Box::pin(async move { Ok(resolving.await?.collect::<Vec<_>>().into_iter()) })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::str::FromStr;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn resolve() -> Result<(), Error> {
+        let test_overrides = [(String::from("Consul.Test"), IpAddr::from([10, 1, 10, 12]))];
+        let test_resolver: Arc<dyn Resolver> = Arc::new(|host: &str| {
+            let test_host = host.to_owned();
+            async move {
+                assert_eq!(test_host, "custom.test");
+                Ok(vec![SocketAddr::from(([10, 1, 10, 11], 0))])
+            }
+        });
+        let mut test_dns = DnsResolver::new(&test_overrides, Some(test_resolver));
+        let test_name = Name::from_str("consul.test").unwrap();
+        let test_addresses: Vec<SocketAddr> = test_dns.call(test_name).await?.collect();
+        assert_eq!(test_addresses, [SocketAddr::from(([10, 1, 10, 12], 0))]);
+        let test_name = Name::from_str("custom.test").unwrap();
+        let test_addresses: Vec<SocketAddr> = test_dns.call(test_name).await?.collect();
+        assert_eq!(test_addresses, [SocketAddr::from(([10, 1, 10, 11], 0))]);
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use crate::query::{AllowNotFound, Blocking};
+use crate::Error;
+
+use hyper::body::Bytes;
+use hyper::http::request::Parts;
+use hyper::{Body, Method, Request, StatusCode};
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::time::Duration;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Idempotent;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Streaming;
+
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    pub statuses: Vec<StatusCode>,
+    pub io_errors: Vec<io::ErrorKind>,
+    pub methods: Vec<Method>,
+    pub idempotent_writes: bool,
+}
+
+impl RetryPolicy {
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> RetryPolicy {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    pub fn io_errors(mut self, io_errors: impl IntoIterator<Item = io::ErrorKind>) -> RetryPolicy {
+        self.io_errors = io_errors.into_iter().collect();
+        self
+    }
+
+    pub fn methods(mut self, methods: impl IntoIterator<Item = Method>) -> RetryPolicy {
+        self.methods = methods.into_iter().collect();
+        self
+    }
+
+    pub fn idempotent_writes(mut self, idempotent_writes: bool) -> RetryPolicy {
+        self.idempotent_writes = idempotent_writes;
+        self
+    }
+
+    pub(crate) fn allows(&self, request: &Request<Body>) -> bool {
+        if self.max_attempts <= 1 {
+            return false;
+        }
+
+        self.methods.contains(request.method())
+            || (self.idempotent_writes && request.extensions().get::<Idempotent>().is_some())
+    }
+
+    pub(crate) fn retries(&self, error: &Error) -> bool {
+        match error {
+            Error::Status { status, .. } => self.statuses.contains(status),
+            Error::Transport(error) => {
+                let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error.as_ref());
+
+                while let Some(error) = source {
+                    if let Some(error) = error.downcast_ref::<io::Error>() {
+                        return self.io_errors.contains(&error.kind());
+                    }
+
+                    if let Some(error) = error.downcast_ref::<hyper::Error>() {
+                        if error.is_incomplete_message() {
+                            return self.io_errors.contains(&io::ErrorKind::UnexpectedEof);
+                        }
+                    }
+
+                    source = error.source();
+                }
+
+                false
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        match self.jitter {
+            true => delay.mul_f64(random()),
+            false => delay,
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+            jitter: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            io_errors: vec![
+                io::ErrorKind::ConnectionRefused,
+                io::ErrorKind::ConnectionReset,
+                io::ErrorKind::ConnectionAborted,
+                io::ErrorKind::BrokenPipe,
+                io::ErrorKind::UnexpectedEof,
+            ],
+            methods: vec![Method::GET, Method::HEAD],
+            idempotent_writes: false,
+        }
+    }
+}
+
+pub(crate) fn rebuild(parts: &Parts, body: Bytes) -> Request<Body> {
+    let mut request = Request::new(Body::from(body));
+
+    *request.method_mut() = parts.method.clone();
+    *request.uri_mut() = parts.uri.clone();
+    *request.version_mut() = parts.version;
+    *request.headers_mut() = parts.headers.clone();
+
+    if let Some(blocking) = parts.extensions.get::<Blocking>() {
+        request.extensions_mut().insert(*blocking);
+    }
+
+    if let Some(idempotent) = parts.extensions.get::<Idempotent>() {
+        request.extensions_mut().insert(*idempotent);
+    }
+
+    if let Some(allow_not_found) = parts.extensions.get::<AllowNotFound>() {
+        request.extensions_mut().insert(*allow_not_found);
+    }
+
+    request
+}
+
+fn random() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allows() -> Result<(), Error> {
+        let test_policy = RetryPolicy::new();
+        let test_get = Request::get("/v1/catalog/nodes").body(Body::empty())?;
+        assert!(test_policy.allows(&test_get));
+        let mut test_put = Request::put("/v1/agent/check/pass/test").body(Body::empty())?;
+        assert!(!test_policy.allows(&test_put));
+        test_put.extensions_mut().insert(Idempotent);
+        assert!(!test_policy.allows(&test_put));
+        let test_policy = RetryPolicy::new().idempotent_writes(true);
+        assert!(test_policy.allows(&test_put));
+        let test_policy = RetryPolicy::new().max_attempts(1);
+        assert!(!test_policy.allows(&test_get));
+        Ok(())
+    }
+
+    #[test]
+    fn retries() {
+        let test_policy = RetryPolicy::new();
+        let test_leader = Error::Status {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            body: String::from("No cluster leader"),
+        };
+        assert!(test_policy.retries(&test_leader));
+        let test_not_found = Error::Status {
+            status: StatusCode::NOT_FOUND,
+            body: String::new(),
+        };
+        assert!(!test_policy.retries(&test_not_found));
+        let test_reset = Error::Transport(io::Error::from(io::ErrorKind::ConnectionReset).into());
+        assert!(test_policy.retries(&test_reset));
+        let test_denied = Error::Transport(io::Error::from(io::ErrorKind::PermissionDenied).into());
+        assert!(!test_policy.retries(&test_denied));
+        assert!(!test_policy.retries(&Error::Timeout));
+    }
+
+    #[test]
+    fn delay() {
+        let test_policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(350))
+            .jitter(false);
+        assert_eq!(test_policy.delay(1), Duration::from_millis(100));
+        assert_eq!(test_policy.delay(2), Duration::from_millis(200));
+        assert_eq!(test_policy.delay(3), Duration::from_millis(350));
+        assert_eq!(test_policy.delay(64), Duration::from_millis(350));
+        let test_policy = test_policy.jitter(true);
+        for test_attempt in 1..10 {
+            assert!(test_policy.delay(test_attempt) <= Duration::from_millis(350));
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{Endpoint, Error};
+
+use http::uri::Authority;
+use hyper::{Body, Request, Response};
+
+use std::future::Future;
+use std::time::Duration;
+
+#[cfg(feature = "tracing")]
+const INDEX_HEADER: &str = "x-consul-index";
+
+#[cfg(feature = "tracing")]
+pub(crate) type Span = tracing::Span;
+
+#[cfg(not(feature = "tracing"))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Span;
+
+#[cfg(feature = "tracing")]
+pub(crate) fn span(endpoint: &Endpoint<'_>, request: &Request<Body>) -> Span {
+    let datacenter = request.uri().query().and_then(|query| {
+        form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key == "dc")
+            .map(|(_, value)| value.into_owned())
+    });
+
+    tracing::info_span!(
+        "consul",
+        endpoint = endpoint.name,
+        method = %request.method(),
+        path = endpoint.path,
+        datacenter = datacenter.as_deref(),
+        status = tracing::field::Empty,
+        index = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    )
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn span(_: &Endpoint<'_>, _: &Request<Body>) -> Span {
+    Span
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) async fn instrument<F>(span: Span, future: F) -> Result<Response<Body>, Error>
+where
+    F: Future<Output = Result<Response<Body>, Error>>,
+{
+    use tracing::Instrument;
+
+    let started = std::time::Instant::now();
+    let result = future.instrument(span.clone()).await;
+
+    span.record("latency_ms", started.elapsed().as_millis() as u64);
+
+    match &result {
+        Ok(response) => {
+            span.record("status", response.status().as_u16());
+
+            if let Some(index) = response.headers().get(INDEX_HEADER) {
+                span.record("index", index.to_str().unwrap_or_default());
+            }
+        }
+        Err(error) => {
+            if let Some(status) = error.status() {
+                span.record("status", status.as_u16());
+            }
+
+            span.in_scope(|| tracing::warn!(error = %error, "consul request failed"));
+        }
+    }
+
+    result
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) async fn instrument<F>(_: Span, future: F) -> Result<Response<Body>, Error>
+where
+    F: Future<Output = Result<Response<Body>, Error>>,
+{
+    future.await
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn retry(attempt: u32, delay: Duration, error: &Error) {
+    tracing::info!(
+        attempt,
+        delay_ms = delay.as_millis() as u64,
+        error = %error,
+        "retrying consul request",
+    );
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn retry(_: u32, _: Duration, _: &Error) {}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn failover(from: &Authority, error: &Error) {
+    tracing::warn!(from = %from, error = %error, "failing over consul server");
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn failover(_: &Authority, _: &Error) {}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn unsupported_proxy(scheme: &str) {
+    tracing::warn!(
+        scheme,
+        "ignoring HTTPS_PROXY, only http proxies are supported"
+    );
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn unsupported_proxy(_: &str) {}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::{RetryPolicy, Runner};
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    #[derive(Clone, Default)]
+    struct TestSubscriber {
+        fields: Arc<Mutex<Vec<String>>>,
+        ids: Arc<AtomicU64>,
+    }
+
+    impl Visit for TestSubscriber {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let test_field = format!("{}={:?}", field.name(), value);
+            self.fields.lock().unwrap().push(test_field);
+        }
+    }
+
+    impl Subscriber for TestSubscriber {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut self.clone());
+            Id::from_u64(self.ids.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            values.record(&mut self.clone());
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn instrument() -> Result<(), crate::Error> {
+        let test_subscriber = TestSubscriber::default();
+        let test_fields = test_subscriber.fields.clone();
+        let _test_guard = tracing::subscriber::set_default(test_subscriber);
+        let mut test_server = mockito::Server::new_async().await;
+        let test_runner = Runner::builder()
+            .address(test_server.url())
+            .token("test_secret_token")
+            .datacenter("test_dc")
+            .retry_policy(RetryPolicy::new().base_delay(Duration::from_millis(1)))
+            .build()?;
+        let mock = test_server
+            .mock("GET", "/v1/catalog/service/test_service")
+            .match_query("dc=test_dc")
+            .with_header("x-consul-index", "42")
+            .create_async()
+            .await;
+        test_runner
+            .catalog_service_nodes("test_service", None)
+            .await?;
+        mock.assert();
+        let mock = test_server
+            .mock("GET", "/v1/status/leader")
+            .match_query("dc=test_dc")
+            .with_status(500)
+            .expect(3)
+            .create_async()
+            .await;
+        assert!(test_runner.status_leader(None).await.is_err());
+        mock.assert();
+        let test_fields = test_fields.lock().unwrap();
+        for test_field in [
+            "endpoint=\"catalog_service_nodes\"",
+            "method=GET",
+            "path=\"/v1/catalog/service/{service}\"",
+            "datacenter=\"test_dc\"",
+            "status=200",
+            "index=\"42\"",
+            "endpoint=\"status_leader\"",
+            "status=500",
+            "attempt=2",
+            "message=retrying consul request",
+        ] {
+            assert!(test_fields.iter().any(|field| field == test_field));
+        }
+        assert!(test_fields
+            .iter()
+            .any(|field| field.starts_with("latency_ms=")));
+        assert!(!test_fields
+            .iter()
+            .any(|field| field.contains("test_secret_token")));
+        Ok(())
+    }
+}
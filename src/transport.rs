@@ -0,0 +1,173 @@
+use crate::Error;
+
+use hyper::client::connect::Connect;
+use hyper::service::Service;
+use hyper::{Body, Client, Request, Response};
+
+use std::future::Future;
+use std::pin::Pin;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type TransportFuture = Pin<Box<dyn Future<Output = Result<Response<Body>, Error>> + Send>>;
+
+pub trait Transport: Send + Sync + 'static {
+    fn send(&self, request: Request<Body>) -> TransportFuture;
+}
+
+impl<F, Fut> Transport for F
+where
+    F: Fn(Request<Body>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<Response<Body>, Error>> + Send + 'static,
+{
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        Box::pin(self(request))
+    }
+}
+
+pub struct HyperTransport<C> {
+    client: Client<C, Body>,
+}
+
+impl<C> HyperTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    pub fn new(connector: C) -> HyperTransport<C> {
+        let client = Client::builder().build(connector);
+
+        HyperTransport { client }
+    }
+
+    pub fn from_client(client: Client<C, Body>) -> HyperTransport<C> {
+        HyperTransport { client }
+    }
+}
+
+impl<C> Transport for HyperTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        let response = self.client.request(request);
+
+        Box::pin(async move { Ok(response.await?) })
+    }
+}
+
+pub struct ServiceTransport<S> {
+    service: S,
+}
+
+impl<S> ServiceTransport<S> {
+    pub fn new(service: S) -> ServiceTransport<S> {
+        ServiceTransport { service }
+    }
+}
+
+impl<S> Transport for ServiceTransport<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + Sync + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    fn send(&self, request: Request<Body>) -> TransportFuture {
+        let mut service = self.service.clone();
+
+        Box::pin(async move {
+            std::future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(|error| Error::Transport(error.into()))?;
+
+            service
+                .call(request)
+                .await
+                .map_err(|error| Error::Transport(error.into()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Runner;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn transport() -> Result<(), Error> {
+        let test_requests = Arc::new(Mutex::new(Vec::new()));
+        let test_recorded = test_requests.clone();
+        let test_transport = move |request: Request<Body>| {
+            let test_recorded = test_recorded.clone();
+            async move {
+                let uri = request.uri().to_string();
+                test_recorded.lock().unwrap().push(uri);
+                Ok(Response::builder()
+                    .header("x-consul-index", "7")
+                    .body(Body::from("[]"))?)
+            }
+        };
+        let test_runner = Runner::builder()
+            .address("http://consul.test:8500")
+            .transport(test_transport)
+            .build()?;
+        let (_, test_meta) = test_runner.catalog_nodes(None).await?;
+        assert_eq!(test_meta.last_index, 7);
+        assert_eq!(
+            test_requests.lock().unwrap().as_slice(),
+            ["http://consul.test:8500/v1/catalog/nodes"],
+        );
+        Ok(())
+    }
+
+    #[derive(Clone)]
+    struct TestService;
+
+    impl Service<Request<Body>> for TestService {
+        type Response = Response<Body>;
+        type Error = std::io::Error;
+        type Future = std::future::Ready<Result<Response<Body>, std::io::Error>>;
+
+        fn poll_ready(
+            &mut self,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), std::io::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<Body>) -> Self::Future {
+            let response = match request.uri().path() {
+                "/v1/status/leader" => Ok(Response::new(Body::from("\"10.1.10.12:8300\""))),
+                _ => Err(std::io::Error::from(std::io::ErrorKind::ConnectionRefused)),
+            };
+
+            std::future::ready(response)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn service_transport() -> Result<(), Error> {
+        let test_runner = Runner::builder()
+            .transport(ServiceTransport::new(TestService))
+            .build()?;
+        let (test_response, _) = test_runner.status_leader(None).await?;
+        let test_body = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_body, "\"10.1.10.12:8300\"");
+        let test_error = test_runner.status_peers(None).await.unwrap_err();
+        assert!(matches!(test_error, Error::Transport(_)));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn transport_status() -> Result<(), Error> {
+        let test_transport = |_: Request<Body>| async {
+            Ok(Response::builder()
+                .status(403)
+                .body(Body::from("Permission denied"))?)
+        };
+        let test_runner = Runner::builder().transport(test_transport).build()?;
+        let test_error = test_runner.catalog_nodes(None).await.unwrap_err();
+        assert_eq!(test_error.status().map(|status| status.as_u16()), Some(403));
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use hyper::client::connect::{Connected, Connection};
+use hyper::service::Service;
+use hyper::Uri;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::UnixStream;
+
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct UnixConnector {
+    path: Arc<Path>,
+    connect_timeout: Option<Duration>,
+}
+
+impl UnixConnector {
+    pub fn new(path: impl Into<PathBuf>) -> UnixConnector {
+        UnixConnector {
+            path: Arc::from(path.into()),
+            connect_timeout: None,
+        }
+    }
+
+    pub fn connect_timeout(mut self, connect_timeout: Option<Duration>) -> UnixConnector {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+}
+
+impl Service<Uri> for UnixConnector {
+    type Response = UnixConnection;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _: Uri) -> Self::Future {
+        let path = self.path.clone();
+        let connect_timeout = self.connect_timeout;
+
+        Box::pin(async move {
+            let connecting = UnixStream::connect(path);
+
+            let stream = match connect_timeout {
+                Some(timeout) => tokio::time::timeout(timeout, connecting)
+                    .await
+                    .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))??,
+                None => connecting.await?,
+            };
+
+            Ok(UnixConnection(stream))
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct UnixConnection(UnixStream);
+
+impl Connection for UnixConnection {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl AsyncRead for UnixConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        context: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(context, buf)
+    }
+}
+
+impl AsyncWrite for UnixConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        context: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(context, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(context)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Runner};
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixListener;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn unix_socket() -> Result<(), Error> {
+        let test_path = std::env::temp_dir().join("sublight_test_consul_http.sock");
+        let _ = std::fs::remove_file(&test_path);
+        let test_listener = UnixListener::bind(&test_path).unwrap();
+        let test_server = std::thread::spawn(move || {
+            let (mut test_stream, _) = test_listener.accept().unwrap();
+            let mut test_buffer = [0; 1024];
+            let test_read = test_stream.read(&mut test_buffer).unwrap();
+            let test_request = String::from_utf8_lossy(&test_buffer[..test_read]).into_owned();
+            let test_body = "\"10.1.10.12:8300\"";
+            let test_response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{}",
+                test_body.len(),
+                test_body,
+            );
+            test_stream.write_all(test_response.as_bytes()).unwrap();
+            test_request
+        });
+        let test_runner = Runner::builder()
+            .address(format!("unix://{}", test_path.display()))
+            .build()?;
+        let (test_response, _) = test_runner.status_leader(None).await?;
+        let test_body = hyper::body::to_bytes(test_response.into_body()).await?;
+        assert_eq!(test_body, "\"10.1.10.12:8300\"");
+        let test_request = test_server.join().unwrap();
+        std::fs::remove_file(&test_path).unwrap();
+        assert!(test_request.starts_with("GET /v1/status/leader HTTP/1.1\r\n"));
+        assert!(test_request.contains("host: localhost\r\n"));
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn unix_socket_missing() -> Result<(), Error> {
+        let test_runner = Runner::builder()
+            .address("unix:///test/missing/consul_http.sock")
+            .build()?;
+        let test_error = test_runner.status_leader(None).await.unwrap_err();
+        assert!(matches!(test_error, Error::Transport(_)));
+        Ok(())
+    }
+}